@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod test;
+
+use std::io::{self, Write};
+
+use simplex::bigvalue::BigValue;
+use simplex::linearprogram::{LinearProgram, Solution};
+use simplex::lp::{self, ParseError};
+use simplex::mps;
+use simplex::objectivevalue::ObjectiveValue;
+use simplex::tabular::SolveStatus;
+use simplex::value;
+
+pub const USAGE: &str = "usage: simplex [--format lp|mps] [--trace] [--quiet] [FILE]
+
+Solves the model in FILE, or standard input when FILE is missing or `-`.
+The format defaults to mps for `.mps` files and lp otherwise.
+
+  --format lp|mps  model file format
+  --trace          print the tableau after every pivot
+  --quiet          print nothing; only set the exit status
+  --help           print this message";
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Format {
+    Lp,
+    Mps,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Options {
+    pub path: Option<String>,
+    pub format: Option<Format>,
+    pub trace: bool,
+    pub quiet: bool,
+    pub help: bool,
+}
+
+impl Options {
+    /// The requested format, or the one implied by the file extension.
+    pub fn format(&self) -> Format {
+        match (self.format, &self.path) {
+            (Some(format), _) => format,
+            (None, Some(path)) if path.to_ascii_lowercase().ends_with(".mps") => Format::Mps,
+            (None, _) => Format::Lp,
+        }
+    }
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" => options.trace = true,
+            "--quiet" | "-q" => options.quiet = true,
+            "--help" | "-h" => options.help = true,
+            "--format" => {
+                let format = args.next().ok_or("--format needs a value")?;
+                options.format = Some(parse_format(&format)?);
+            }
+            _ if arg.starts_with("--format=") => {
+                options.format = Some(parse_format(&arg["--format=".len()..])?);
+            }
+            "-" => options.path = None,
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ if options.path.is_some() => return Err("expected a single model file".to_string()),
+            _ => options.path = Some(arg),
+        }
+    }
+    Ok(options)
+}
+
+fn parse_format(format: &str) -> Result<Format, String> {
+    match format.to_ascii_lowercase().as_str() {
+        "lp" => Ok(Format::Lp),
+        "mps" => Ok(Format::Mps),
+        _ => Err(format!("unknown format {}", format)),
+    }
+}
+
+/// Reads the model with exact arbitrary-precision values, so neither long
+/// literals nor the solve can overflow.
+pub fn parse_program(input: &str, format: Format) -> Result<LinearProgram<BigValue>, ParseError> {
+    match format {
+        Format::Lp => lp::parse(input),
+        Format::Mps => mps::parse(input),
+    }
+}
+
+/// Prints the status, and the objective and variable values of optimal
+/// solutions.
+pub fn report<O: ObjectiveValue<BigValue>>(
+    solution: &Solution<BigValue, O>,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "Status: {:?}", solution.status)?;
+    match solution.status {
+        SolveStatus::Optimal => {
+            writeln!(
+                out,
+                "Objective: {}",
+                value::format_decimal(&solution.objective_value)
+            )?;
+            for (name, var_value) in &solution.values {
+                writeln!(out, "{} = {}", name, value::format_decimal(var_value))?;
+            }
+        }
+        SolveStatus::Infeasible => {
+            writeln!(
+                out,
+                "Violated constraints: {}",
+                solution.infeasible_constraints.join(", ")
+            )?;
+        }
+        SolveStatus::Unbounded | SolveStatus::IterationLimit => {}
+    }
+    Ok(())
+}
+
+pub fn exit_code(status: SolveStatus) -> i32 {
+    match status {
+        SolveStatus::Optimal => 0,
+        SolveStatus::Infeasible | SolveStatus::Unbounded | SolveStatus::IterationLimit => 1,
+    }
+}
@@ -0,0 +1,121 @@
+use simplex::bigvalue::BigValue;
+use simplex::linearprogram::Solution;
+use simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use simplex::tabular::{EmptyObserver, SolveStatus};
+
+use crate::cli::{self as sut};
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+fn report(solution: &Solution<BigValue, MObjectiveValue<BigValue>>) -> String {
+    let mut out = vec![];
+    sut::report(solution, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn solve(input: &str) -> Solution<BigValue, MObjectiveValue<BigValue>> {
+    sut::parse_program(input, sut::Format::Lp)
+        .unwrap()
+        .solve(&mut EmptyObserver {})
+}
+
+#[test]
+fn parses_flags_and_path() {
+    let options = sut::parse_args(args(&["--trace", "--format", "MPS", "model.txt"])).unwrap();
+    assert_eq!(
+        sut::Options {
+            path: Some("model.txt".to_string()),
+            format: Some(sut::Format::Mps),
+            trace: true,
+            quiet: false,
+            help: false,
+        },
+        options
+    );
+    let options = sut::parse_args(args(&["-q", "--format=lp", "-"])).unwrap();
+    assert!(options.quiet);
+    assert_eq!(None, options.path);
+    assert_eq!(sut::Format::Lp, options.format());
+}
+
+#[test]
+fn infers_format_from_extension() {
+    let options = sut::parse_args(args(&["afiro.MPS"])).unwrap();
+    assert_eq!(sut::Format::Mps, options.format());
+    let options = sut::parse_args(args(&["model.lp"])).unwrap();
+    assert_eq!(sut::Format::Lp, options.format());
+    assert_eq!(sut::Format::Lp, sut::Options::default().format());
+}
+
+#[test]
+fn rejects_bad_arguments() {
+    assert_eq!(
+        Err("unknown option --verbose".to_string()),
+        sut::parse_args(args(&["--verbose"]))
+    );
+    assert_eq!(
+        Err("unknown format csv".to_string()),
+        sut::parse_args(args(&["--format", "csv"]))
+    );
+    assert_eq!(
+        Err("--format needs a value".to_string()),
+        sut::parse_args(args(&["--format"]))
+    );
+    assert_eq!(
+        Err("expected a single model file".to_string()),
+        sut::parse_args(args(&["a.lp", "b.lp"]))
+    );
+}
+
+#[test]
+fn reports_optimal_solution() {
+    let solution = solve("max 3x + 2y\nst\n x + y <= 4\n -x + y = 1\nend\n");
+    assert_eq!(
+        "Status: Optimal\nObjective: 9.5\nx = 1.5\ny = 2.5\n",
+        report(&solution)
+    );
+    assert_eq!(0, sut::exit_code(solution.status));
+}
+
+#[test]
+fn solves_models_that_overflow_u64_fractions() {
+    let solution = solve(
+        "max
+ obj: 1.234567 x + 2.345678 y + 3.456789 z
+st
+ c1: 0.312345 x + 0.723456 y + 0.134567 z <= 1.145678
+ c2: 0.456789 x + 0.167891 y + 0.578912 z <= 2.789123
+ c3: 0.891234 x + 0.512345 y + 0.623456 z <= 3.912345
+end
+",
+    );
+    assert_eq!(
+        "Status: Optimal
+Objective: 17.63039680491181273
+x = 0
+y = 0.72666538838002347
+z = 4.60712927228920713
+",
+        report(&solution)
+    );
+}
+
+#[test]
+fn reports_infeasible_constraints() {
+    let solution = solve("max x\nst\n cap: x <= 1\n demand: x >= 2\nend\n");
+    assert_eq!(
+        "Status: Infeasible\nViolated constraints: demand\n",
+        report(&solution)
+    );
+    assert_eq!(1, sut::exit_code(solution.status));
+}
+
+#[test]
+fn exits_nonzero_when_unbounded() {
+    let solution = solve("max x\nst\n x - y <= 1\nend\n");
+    assert_eq!(SolveStatus::Unbounded, solution.status);
+    assert_eq!("Status: Unbounded\n", report(&solution));
+    assert_eq!(1, sut::exit_code(solution.status));
+}
@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception, clippy::upper_case_acronyms)]
+
+mod simplex;
+
+pub use simplex::*;
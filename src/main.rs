@@ -1,40 +1,50 @@
-mod simplex;
+mod cli;
 
-use std::io::stdout;
+use std::io::{stdin, stdout, Read};
+use std::process::exit;
 
-use fraction::Fraction;
+use simplex::bigvalue::BigValue;
+use simplex::linearprogram::Solution;
+use simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use simplex::tabular::write_observer::WriteObserver;
+use simplex::tabular::EmptyObserver;
 
-use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
-use crate::simplex::tabular::primal::solve;
-use crate::simplex::tabular::write_observer::WriteObserver;
-use crate::simplex::tabular::Problem;
-use crate::simplex::value::Value;
-use crate::simplex::Constraint;
-use crate::simplex::Operator;
+fn fail(message: &str) -> ! {
+    eprintln!("simplex: {}", message);
+    exit(2)
+}
 
 fn main() {
-    let mut writer = stdout();
-    let mut observer = WriteObserver::new(&mut writer);
-    let objective_fn_coeffs = vec![Fraction::from(1), Fraction::from(2)];
-    let functional_constraints = vec![
-        Constraint {
-            operator: Operator::LESSTHANEQUAL,
-            coefficients: vec![
-                Value::from(Fraction::from(1)),
-                Value::from(Fraction::from(1)),
-            ],
-            bound: Value::from(Fraction::from(4)),
-        },
-        Constraint {
-            operator: Operator::LESSTHANEQUAL,
-            coefficients: vec![
-                Value::from(Fraction::from(1)),
-                Value::from(Fraction::from(3)),
-            ],
-            bound: Value::from(Fraction::from(8)),
-        },
-    ];
-    let problem =
-        Problem::<Value, MObjectiveValue>::new(&objective_fn_coeffs, &functional_constraints);
-    solve(problem, &mut observer);
+    let options = cli::parse_args(std::env::args().skip(1))
+        .unwrap_or_else(|message| fail(&format!("{}\n\n{}", message, cli::USAGE)));
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+    let input = match &options.path {
+        Some(path) => {
+            std::fs::read_to_string(path).unwrap_or_else(|err| fail(&format!("{}: {}", path, err)))
+        }
+        None => {
+            let mut input = String::new();
+            stdin()
+                .read_to_string(&mut input)
+                .unwrap_or_else(|err| fail(&format!("stdin: {}", err)));
+            input
+        }
+    };
+    let program = cli::parse_program(&input, options.format()).unwrap_or_else(|err| {
+        let source = options.path.as_deref().unwrap_or("stdin");
+        fail(&format!("{}: {}", source, err))
+    });
+    let solution: Solution<BigValue, MObjectiveValue<BigValue>> = if options.trace && !options.quiet
+    {
+        program.solve(&mut WriteObserver::new(&mut stdout()))
+    } else {
+        program.solve(&mut EmptyObserver {})
+    };
+    if !options.quiet {
+        cli::report(&solution, &mut stdout()).unwrap_or_else(|err| fail(&err.to_string()));
+    }
+    exit(cli::exit_code(solution.status))
 }
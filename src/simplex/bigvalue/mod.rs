@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod test;
+
+use fraction::{BigFraction, BigUint, GenericFraction};
+
+use crate::simplex::rowvalue::{Decimal, RowValue};
+
+/// An arbitrary-precision rational. Slower than `Value`, but exact solves
+/// never overflow.
+pub type BigValue = BigFraction;
+
+pub fn zero() -> BigValue {
+    BigFraction::from(0)
+}
+
+pub fn one() -> BigValue {
+    BigFraction::from(1)
+}
+
+impl RowValue for BigValue {
+    fn zero() -> Self {
+        zero()
+    }
+
+    fn one() -> Self {
+        one()
+    }
+
+    fn is_finite(&self) -> bool {
+        !matches!(self, GenericFraction::Infinity(_) | GenericFraction::NaN)
+    }
+
+    fn from_decimal(decimal: &Decimal) -> Option<Self> {
+        let numerator = decimal.digits.parse::<BigUint>().ok()?;
+        let power = BigUint::from(10u32).pow(decimal.exponent.unsigned_abs());
+        let magnitude = if decimal.exponent >= 0 {
+            BigFraction::new(numerator * power, BigUint::from(1u32))
+        } else {
+            BigFraction::new(numerator, power)
+        };
+        Some(if decimal.negative {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+}
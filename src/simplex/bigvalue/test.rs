@@ -0,0 +1,71 @@
+use std::panic;
+
+use fraction::{BigFraction, Fraction};
+
+use crate::simplex::bigvalue::{self as sut, BigValue};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::primal;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::{EmptyObserver, Problem, SolveStatus};
+use crate::simplex::{Constraint, Operator};
+
+/// Coefficients near 2^32, so the first pivot multiplies two of them and
+/// overflows `u64`.
+fn overflowing_problem<R: RowValue + From<u64>, O: ObjectiveValue<R>>() -> Problem<R, O> {
+    let constraint = |a: u64, b: u64, bound: u64| Constraint {
+        operator: Operator::LESSTHANEQUAL,
+        coefficients: vec![R::from(a), R::from(b)],
+        bound: R::from(bound),
+    };
+    Problem::new(
+        &vec![R::from(4294967291), R::from(4294967279)],
+        &[
+            constraint(4294967231, 4294967197, 4294967189),
+            constraint(4294967161, 4294967143, 4294967111),
+        ],
+    )
+}
+
+#[test]
+fn big_value_detects_non_finite_values() {
+    assert!(RowValue::is_finite(&sut::one()));
+    assert!(!RowValue::is_finite(&(sut::one() / sut::zero())));
+    assert!(!RowValue::is_finite(&(sut::zero() / sut::zero())));
+}
+
+#[test]
+fn big_value_solves_problem_that_overflows_fraction() {
+    let result = primal::solve(
+        overflowing_problem::<BigValue, MObjectiveValue<BigValue>>(),
+        &mut EmptyObserver {},
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(
+        vec![sut::zero(), BigFraction::new(4294967111u64, 4294967143u64)],
+        result.point
+    );
+    assert_eq!(
+        MObjectiveValue::from(BigFraction::new(18446743206126160969u64, 4294967143u64)),
+        result.objective_value
+    );
+}
+
+/// A debug build panics on the overflow and a release build wraps around,
+/// so the u64 solve never reaches the exact objective value.
+#[test]
+fn fraction_cannot_solve_overflowing_problem() {
+    let solve = || {
+        primal::solve(
+            overflowing_problem::<Fraction, MObjectiveValue>(),
+            &mut EmptyObserver {},
+        )
+        .objective_value
+    };
+    if let Ok(objective_value) = panic::catch_unwind(solve) {
+        assert_ne!(
+            MObjectiveValue::from(Fraction::new(18446743206126160969u64, 4294967143u64)),
+            objective_value
+        );
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::SolveResult;
+use crate::simplex::{Coefficients, Constraint, ObjectiveSense, Operator};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum VariableSign {
+    NonNegative,
+    NonPositive,
+    Free,
+}
+
+/// The dual of a linear program over nonnegative variables. It has one
+/// variable per primal constraint and one constraint per primal variable,
+/// and optimizes in the opposite sense.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DualProgram<R: RowValue> {
+    pub sense: ObjectiveSense,
+    pub objective_coeffs: Coefficients<R>,
+    pub constraints: Vec<Constraint<R>>,
+    pub variable_signs: Vec<VariableSign>,
+}
+
+/// The evidence that a primal point and dual vector are both optimal: both
+/// are feasible and their objective values agree. Feasibility and agreement
+/// are judged by the `RowValue` sign checks, so an inexact type's
+/// tolerances absorb rounding.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Certificate<R: RowValue> {
+    pub primal_objective: R,
+    pub dual_objective: R,
+    pub primal_feasible: bool,
+    pub dual_feasible: bool,
+}
+
+impl<R: RowValue> Certificate<R> {
+    pub fn is_optimal(&self) -> bool {
+        self.primal_feasible
+            && self.dual_feasible
+            && is_zero(self.primal_objective.clone() + -self.dual_objective.clone())
+    }
+}
+
+impl<R: RowValue> DualProgram<R> {
+    /// Dual variables are signed so that each one is the rate at which the
+    /// primal objective changes with its constraint's bound.
+    pub fn new(
+        sense: ObjectiveSense,
+        objective_coeffs: &Coefficients<R>,
+        functional_constraints: &[Constraint<R>],
+    ) -> Self {
+        let (dual_sense, dual_operator) = match sense {
+            ObjectiveSense::Maximize => (ObjectiveSense::Minimize, Operator::GREATERTHANEQUAL),
+            ObjectiveSense::Minimize => (ObjectiveSense::Maximize, Operator::LESSTHANEQUAL),
+        };
+        Self {
+            sense: dual_sense,
+            objective_coeffs: functional_constraints
+                .iter()
+                .map(|constraint| constraint.bound.clone())
+                .collect(),
+            constraints: objective_coeffs
+                .iter()
+                .enumerate()
+                .map(|(var, coeff)| Constraint {
+                    operator: dual_operator.clone(),
+                    coefficients: functional_constraints
+                        .iter()
+                        .map(|constraint| constraint.coefficients[var].clone())
+                        .collect(),
+                    bound: coeff.clone(),
+                })
+                .collect(),
+            variable_signs: functional_constraints
+                .iter()
+                .map(|constraint| variable_sign(sense, &constraint.operator))
+                .collect(),
+        }
+    }
+
+    pub fn objective_value(&self, y: &[R]) -> R {
+        dot(&self.objective_coeffs, y)
+    }
+
+    pub fn is_feasible(&self, y: &[R]) -> bool {
+        let signs_hold = self
+            .variable_signs
+            .iter()
+            .zip(y)
+            .all(|(sign, value)| match sign {
+                VariableSign::NonNegative => !value.is_negative(),
+                VariableSign::NonPositive => !value.is_positive(),
+                VariableSign::Free => true,
+            });
+        signs_hold
+            && self
+                .constraints
+                .iter()
+                .all(|constraint| is_satisfied(constraint, y))
+    }
+}
+
+/// Checks the point and dual vector of a solve against the original model.
+pub fn certify<R: RowValue, O: ObjectiveValue<R>>(
+    sense: ObjectiveSense,
+    objective_coeffs: &Coefficients<R>,
+    functional_constraints: &[Constraint<R>],
+    result: &SolveResult<R, O>,
+) -> Certificate<R> {
+    let dual = DualProgram::new(sense, objective_coeffs, functional_constraints);
+    Certificate {
+        primal_objective: dot(objective_coeffs, &result.point),
+        dual_objective: dual.objective_value(&result.dual_values),
+        primal_feasible: result.point.iter().all(|value| !value.is_negative())
+            && functional_constraints
+                .iter()
+                .all(|constraint| is_satisfied(constraint, &result.point)),
+        dual_feasible: dual.is_feasible(&result.dual_values),
+    }
+}
+
+fn variable_sign(sense: ObjectiveSense, operator: &Operator) -> VariableSign {
+    match (sense, operator) {
+        (_, Operator::EQUAL) => VariableSign::Free,
+        (ObjectiveSense::Maximize, Operator::LESSTHANEQUAL)
+        | (ObjectiveSense::Minimize, Operator::GREATERTHANEQUAL) => VariableSign::NonNegative,
+        (ObjectiveSense::Maximize, Operator::GREATERTHANEQUAL)
+        | (ObjectiveSense::Minimize, Operator::LESSTHANEQUAL) => VariableSign::NonPositive,
+    }
+}
+
+fn is_satisfied<R: RowValue>(constraint: &Constraint<R>, point: &[R]) -> bool {
+    let excess = dot(&constraint.coefficients, point) + -constraint.bound.clone();
+    match constraint.operator {
+        Operator::LESSTHANEQUAL => !excess.is_positive(),
+        Operator::GREATERTHANEQUAL => !excess.is_negative(),
+        Operator::EQUAL => is_zero(excess),
+    }
+}
+
+fn is_zero<R: RowValue>(value: R) -> bool {
+    !value.is_positive() && !value.is_negative()
+}
+
+fn dot<R: RowValue>(coefficients: &[R], values: &[R]) -> R {
+    coefficients
+        .iter()
+        .zip(values)
+        .fold(R::zero(), |sum, (coeff, value)| {
+            sum + coeff.clone() * value.clone()
+        })
+}
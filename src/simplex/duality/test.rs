@@ -0,0 +1,153 @@
+use crate::simplex::duality::{self as sut};
+use crate::simplex::float::Float;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::{EmptyObserver, Problem, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, ObjectiveSense, Operator};
+
+fn production_constraints() -> Vec<Constraint<Value>> {
+    vec![
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+        upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(8, 1)),
+    ]
+}
+
+fn covering_constraints() -> Vec<Constraint<Value>> {
+    vec![
+        lower_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(4, 1)),
+        equality_constraint(vec![frac(1, 1), -frac(1, 1), value::zero()], value::zero()),
+    ]
+}
+
+#[test]
+fn builds_dual_of_maximization() {
+    let dual = sut::DualProgram::new(
+        ObjectiveSense::Maximize,
+        &vec![frac(1, 1), frac(2, 1)],
+        &production_constraints(),
+    );
+    assert_eq!(
+        sut::DualProgram {
+            sense: ObjectiveSense::Minimize,
+            objective_coeffs: vec![frac(4, 1), frac(8, 1)],
+            constraints: vec![
+                lower_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(1, 1)),
+                lower_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(2, 1)),
+            ],
+            variable_signs: vec![sut::VariableSign::NonNegative; 2],
+        },
+        dual
+    );
+}
+
+#[test]
+fn builds_dual_of_minimization() {
+    let dual = sut::DualProgram::new(
+        ObjectiveSense::Minimize,
+        &vec![frac(2, 1), frac(3, 1), frac(4, 1)],
+        &covering_constraints(),
+    );
+    assert_eq!(ObjectiveSense::Maximize, dual.sense);
+    assert_eq!(
+        vec![sut::VariableSign::NonNegative, sut::VariableSign::Free],
+        dual.variable_signs
+    );
+    assert_eq!(Operator::LESSTHANEQUAL, dual.constraints[1].operator);
+    assert_eq!(
+        vec![frac(1, 1), -frac(1, 1)],
+        dual.constraints[1].coefficients
+    );
+    assert_eq!(frac(3, 1), dual.constraints[1].bound);
+}
+
+#[test]
+fn certifies_optimal_maximization() {
+    let coeffs = vec![frac(1, 1), frac(2, 1)];
+    let constraints = production_constraints();
+    let result = primal::solve(
+        PrimalProblem::new(&coeffs, &constraints),
+        &mut EmptyObserver {},
+    );
+    assert_eq!(vec![frac(1, 2), frac(1, 2)], result.dual_values);
+    let certificate = sut::certify(ObjectiveSense::Maximize, &coeffs, &constraints, &result);
+    assert_eq!(
+        sut::Certificate {
+            primal_objective: frac(6, 1),
+            dual_objective: frac(6, 1),
+            primal_feasible: true,
+            dual_feasible: true,
+        },
+        certificate
+    );
+    assert!(certificate.is_optimal());
+}
+
+#[test]
+fn certifies_optimal_minimization() {
+    let coeffs = vec![frac(2, 1), frac(3, 1), frac(4, 1)];
+    let constraints = covering_constraints();
+    let result = primal::solve(
+        PrimalProblem::with_sense(ObjectiveSense::Minimize, &coeffs, &constraints),
+        &mut EmptyObserver {},
+    );
+    assert_eq!(vec![frac(5, 2), -frac(1, 2)], result.dual_values);
+    let certificate = sut::certify(ObjectiveSense::Minimize, &coeffs, &constraints, &result);
+    assert_eq!(frac(10, 1), certificate.dual_objective);
+    assert!(certificate.is_optimal());
+}
+
+#[test]
+fn rejects_infeasible_dual_vector() {
+    let dual = sut::DualProgram::new(
+        ObjectiveSense::Maximize,
+        &vec![frac(1, 1), frac(2, 1)],
+        &production_constraints(),
+    );
+    assert!(dual.is_feasible(&[frac(1, 2), frac(1, 2)]));
+    assert!(!dual.is_feasible(&[frac(1, 1), value::zero()]));
+    assert!(!dual.is_feasible(&[-frac(1, 1), frac(1, 1)]));
+    assert_eq!(frac(8, 1), dual.objective_value(&[frac(1, 1), frac(1, 2)]));
+}
+
+fn float_constraint(coefficients: &[f64], bound: f64) -> Constraint<Float> {
+    Constraint {
+        operator: Operator::LESSTHANEQUAL,
+        coefficients: coefficients.iter().copied().map(Float::new).collect(),
+        bound: Float::new(bound),
+    }
+}
+
+#[test]
+fn certifies_float_solve_despite_rounding() {
+    let coeffs: Vec<Float> = [0.3, 0.2, 0.7].into_iter().map(Float::new).collect();
+    let constraints = vec![
+        float_constraint(&[0.1, 0.7, 0.3], 0.9),
+        float_constraint(&[0.6, 0.2, 0.3], 0.7),
+        float_constraint(&[0.3, 0.1, 0.9], 1.1),
+    ];
+    let result = primal::solve(
+        Problem::<Float, MObjectiveValue<Float>>::new(&coeffs, &constraints),
+        &mut EmptyObserver {},
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    let certificate = sut::certify(ObjectiveSense::Maximize, &coeffs, &constraints, &result);
+    assert!(certificate.primal_feasible);
+    assert!(certificate.dual_feasible);
+    assert!(certificate.is_optimal());
+}
+
+#[test]
+fn accepts_float_dual_vector_within_rounding() {
+    // 0.7 + 0.1 rounds to just below 0.8.
+    let dual = sut::DualProgram::new(
+        ObjectiveSense::Maximize,
+        &vec![Float::new(0.8)],
+        &[float_constraint(&[0.7], 1.0), float_constraint(&[0.1], 1.0)],
+    );
+    assert!(dual.is_feasible(&[Float::new(1.0), Float::new(1.0)]));
+    assert!(!dual.is_feasible(&[Float::new(1.0), Float::new(0.9)]));
+}
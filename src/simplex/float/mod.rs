@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test;
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg};
+
+use crate::simplex::rowvalue::{Decimal, RowValue};
+
+/// Tolerances for floating point solves. Values within `FEASIBILITY` of
+/// zero count as zero in ratio tests and feasibility checks, and objective
+/// coefficients above `-OPTIMALITY` count as optimal.
+pub trait Tolerances: Clone + Copy + Debug {
+    const FEASIBILITY: f64;
+    const OPTIMALITY: f64;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DefaultTolerances;
+
+impl Tolerances for DefaultTolerances {
+    const FEASIBILITY: f64 = 1e-9;
+    const OPTIMALITY: f64 = 1e-9;
+}
+
+/// A floating point `RowValue`. Values are ordered exactly, by
+/// `f64::total_cmp`; the tolerances of `T` only apply to the sign checks of
+/// `RowValue`.
+#[derive(Clone, Copy)]
+pub struct Float<T: Tolerances = DefaultTolerances>(f64, PhantomData<T>);
+
+impl<T: Tolerances> Float<T> {
+    /// Negative zero is stored as zero, so the two compare equal.
+    pub fn new(value: f64) -> Self {
+        Float(value + 0.0, PhantomData)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl<T: Tolerances> From<f64> for Float<T> {
+    fn from(value: f64) -> Self {
+        Float::new(value)
+    }
+}
+
+impl<T: Tolerances> PartialEq for Float<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Tolerances> Eq for Float<T> {}
+
+impl<T: Tolerances> PartialOrd for Float<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Tolerances> Ord for Float<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<T: Tolerances> Add for Float<T> {
+    type Output = Float<T>;
+
+    fn add(self, rhs: Float<T>) -> Self::Output {
+        Float::new(self.0 + rhs.0)
+    }
+}
+
+impl<T: Tolerances> Neg for Float<T> {
+    type Output = Float<T>;
+
+    fn neg(self) -> Self::Output {
+        Float::new(-self.0)
+    }
+}
+
+impl<T: Tolerances> Mul for Float<T> {
+    type Output = Float<T>;
+
+    fn mul(self, rhs: Float<T>) -> Self::Output {
+        Float::new(self.0 * rhs.0)
+    }
+}
+
+impl<T: Tolerances> Div for Float<T> {
+    type Output = Float<T>;
+
+    fn div(self, rhs: Float<T>) -> Self::Output {
+        Float::new(self.0 / rhs.0)
+    }
+}
+
+impl<T: Tolerances> Debug for Float<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T: Tolerances> Display for Float<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: Tolerances> RowValue for Float<T> {
+    fn zero() -> Self {
+        Float::new(0.0)
+    }
+
+    fn one() -> Self {
+        Float::new(1.0)
+    }
+
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// The nearest `f64`, or `None` if the decimal overflows it.
+    fn from_decimal(decimal: &Decimal) -> Option<Self> {
+        let magnitude = format!("{}e{}", decimal.digits, decimal.exponent)
+            .parse::<f64>()
+            .ok()
+            .filter(|magnitude| magnitude.is_finite())?;
+        Some(Float::new(if decimal.negative {
+            -magnitude
+        } else {
+            magnitude
+        }))
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 > T::FEASIBILITY
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < -T::FEASIBILITY
+    }
+
+    fn is_improving(&self) -> bool {
+        self.0 < -T::OPTIMALITY
+    }
+}
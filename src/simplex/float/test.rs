@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+
+use crate::simplex::float::{self as sut, Tolerances};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::primal::{self, mobjectivevalue::MObjectiveValue};
+use crate::simplex::tabular::{EmptyObserver, Problem, SolveStatus};
+use crate::simplex::{Constraint, ObjectiveSense, Operator};
+
+#[derive(Clone, Copy, Debug)]
+struct Loose;
+
+impl Tolerances for Loose {
+    const FEASIBILITY: f64 = 1e-3;
+    const OPTIMALITY: f64 = 1e-3;
+}
+
+fn float(value: f64) -> sut::Float {
+    sut::Float::new(value)
+}
+
+#[test]
+fn float_orders_exactly() {
+    assert_ne!(float(0.1 + 0.2), float(0.3));
+    assert_eq!(Ordering::Less, float(1.0).cmp(&float(1.0 + 1e-12)));
+    assert!(float(-1e-12) < float(0.0));
+    assert_eq!(float(0.0), -float(0.0));
+}
+
+#[test]
+fn float_order_is_transitive_near_tolerance() {
+    let (a, b, c) = (float(0.0), float(0.6e-9), float(1.2e-9));
+    assert!(a < b && b < c && a < c);
+}
+
+#[test]
+fn float_signs_within_feasibility_tolerance() {
+    assert!(!float(1e-12).is_positive());
+    assert!(!float(-1e-12).is_negative());
+    assert!(float(1e-6).is_positive());
+    assert!(float(-1e-6).is_negative());
+}
+
+#[test]
+fn float_improves_beyond_optimality_tolerance() {
+    assert!(!float(0.0).is_improving());
+    assert!(!float(-1e-12).is_improving());
+    assert!(float(-1e-6).is_improving());
+}
+
+#[test]
+fn float_uses_configured_tolerances() {
+    let loose = |value: f64| sut::Float::<Loose>::new(value);
+    assert!(!loose(0.0005).is_positive());
+    assert!(!loose(-0.0005).is_improving());
+    assert!(loose(0.01).is_positive());
+}
+
+#[test]
+fn float_arithmetic() {
+    assert_eq!(float(3.5), float(1.5) + float(2.0));
+    assert_eq!(float(-2.0), -float(2.0));
+    assert_eq!(float(3.0), float(1.5) * float(2.0));
+    assert_eq!(float(0.75), float(1.5) / float(2.0));
+    assert_eq!(1.5, float(1.5).value());
+}
+
+#[test]
+fn float_detects_non_finite_values() {
+    assert!(float(1.0).is_finite());
+    assert!(!(float(1.0) / float(0.0)).is_finite());
+    assert!(!(float(0.0) / float(0.0)).is_finite());
+}
+
+#[test]
+fn float_formats_like_f64() {
+    assert_eq!("2.5", format!("{}", float(2.5)));
+    assert_eq!("2.5", format!("{:?}", float(2.5)));
+}
+
+type FloatProblem = Problem<sut::Float, MObjectiveValue<sut::Float>>;
+
+fn constraint(operator: Operator, coefficients: &[f64], bound: f64) -> Constraint<sut::Float> {
+    Constraint {
+        operator,
+        coefficients: coefficients.iter().map(|&c| float(c)).collect(),
+        bound: float(bound),
+    }
+}
+
+fn assert_close(expected: &[f64], actual: &[sut::Float]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual) {
+        assert!(
+            (expected - actual.value()).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+}
+
+#[test]
+fn solves_in_floating_point() {
+    let problem = FloatProblem::new(
+        &vec![float(1.0), float(2.0)],
+        &[
+            constraint(Operator::LESSTHANEQUAL, &[1.0, 1.0], 4.0),
+            constraint(Operator::LESSTHANEQUAL, &[1.0, 3.0], 8.0),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_close(&[6.0], &[result.objective_value.finite_part()]);
+    assert_close(&[2.0, 2.0], &result.point);
+    assert_close(&[0.5, 0.5], &result.dual_values);
+}
+
+#[test]
+fn solves_artificial_rows_in_floating_point() {
+    let problem = FloatProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &vec![float(0.2), float(0.3), float(0.4)],
+        &[
+            constraint(Operator::GREATERTHANEQUAL, &[1.0, 1.0, 1.0], 0.4),
+            constraint(Operator::EQUAL, &[1.0, -1.0, 0.0], 0.0),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_close(&[0.1], &[result.objective_value.finite_part()]);
+    assert_close(&[0.2, 0.2, 0.0], &result.point);
+    assert_close(&[0.25, -0.05], &result.dual_values);
+}
+
+#[test]
+fn reports_infeasibility_in_floating_point() {
+    let problem = FloatProblem::new(
+        &vec![float(1.0)],
+        &[
+            constraint(Operator::LESSTHANEQUAL, &[1.0], 1.0),
+            constraint(Operator::GREATERTHANEQUAL, &[1.0], 2.0),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.infeasible_constraints);
+}
+
+#[test]
+fn ignores_rounding_left_on_artificial_rows() {
+    // The redundant row keeps its artificial variable basic at
+    // 0.3 - 0.1 - 0.2, a rounding error away from zero.
+    let problem = FloatProblem::new(
+        &vec![float(1.0), float(1.0)],
+        &[
+            constraint(Operator::EQUAL, &[1.0, 0.0], 0.1),
+            constraint(Operator::EQUAL, &[0.0, 1.0], 0.2),
+            constraint(Operator::EQUAL, &[1.0, 1.0], 0.3),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_close(&[0.1, 0.2], &result.point);
+}
@@ -0,0 +1,476 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::revised;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::sparse::{SparseCoefficients, SparseConstraint};
+use crate::simplex::tabular::primal::bounded::{self, BoundedProblem};
+use crate::simplex::tabular::primal::strategy::Strategy;
+use crate::simplex::tabular::sparse::{self, SparseProblem};
+use crate::simplex::tabular::{
+    primal, Problem, ProblemObserver, SolveOptions, SolveResult, SolveStatus,
+};
+use crate::simplex::{Coefficients, Constraint, ObjectiveSense, Operator, Variable};
+
+pub type Term<R> = (Variable, R);
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct NamedConstraint<R: RowValue> {
+    pub name: String,
+    pub terms: Vec<Term<R>>,
+    pub operator: Operator,
+    pub bound: R,
+    /// An MPS range, which also bounds the row from the other side: `<=`
+    /// and `>=` rows hold within `|range|` of `bound`, and an `=` row holds
+    /// between `bound` and `bound + range`.
+    pub range: Option<R>,
+}
+
+impl<R: RowValue> NamedConstraint<R> {
+    /// The operator of the row at `bound`. A ranged equality is the side of
+    /// its range that `bound` is on.
+    fn bound_operator(&self) -> Operator {
+        match (&self.operator, &self.range) {
+            (Operator::EQUAL, Some(range)) if *range < R::zero() => Operator::LESSTHANEQUAL,
+            (Operator::EQUAL, Some(_)) => Operator::GREATERTHANEQUAL,
+            (operator, _) => operator.clone(),
+        }
+    }
+
+    /// The operator and bound of the row on the other side of the range.
+    fn range_row(&self) -> Option<(Operator, R)> {
+        let range = self.range.clone()?;
+        let magnitude = if range < R::zero() { -range } else { range };
+        Some(if self.bound_operator() == Operator::GREATERTHANEQUAL {
+            (Operator::LESSTHANEQUAL, self.bound.clone() + magnitude)
+        } else {
+            (Operator::GREATERTHANEQUAL, self.bound.clone() + -magnitude)
+        })
+    }
+}
+
+/// The range a variable may take. `None` leaves that side unbounded.
+#[derive(PartialEq, Debug, Clone)]
+pub struct VariableBounds<R: RowValue> {
+    pub lower: Option<R>,
+    pub upper: Option<R>,
+}
+
+impl<R: RowValue> Default for VariableBounds<R> {
+    fn default() -> Self {
+        Self {
+            lower: Some(R::zero()),
+            upper: None,
+        }
+    }
+}
+
+/// A model built from named variables and sparse constraints, lowered to a
+/// dense `Problem` only when it is solved.
+///
+/// The tableau only knows non-negative columns, so lowering writes each
+/// variable as `offset + sum(sign * column)`: a variable with a lower bound
+/// is shifted onto it, one with only an upper bound is mirrored below it,
+/// and a free variable is split into positive and negative parts. A
+/// variable bounded on both sides also gets a `"{name}.ub"` row, unless it
+/// is solved with `solve_bounded`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LinearProgram<R: RowValue> {
+    pub sense: ObjectiveSense,
+    pub variables: Vec<String>,
+    pub bounds: Vec<VariableBounds<R>>,
+    pub objective: Vec<Term<R>>,
+    pub constraints: Vec<NamedConstraint<R>>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Solution<R: RowValue, O: ObjectiveValue<R>> {
+    pub status: SolveStatus,
+    pub objective_value: O,
+    pub values: Vec<(String, R)>,
+    pub infeasible_constraints: Vec<String>,
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> Solution<R, O> {
+    pub fn value(&self, name: &str) -> Option<&R> {
+        self.values
+            .iter()
+            .find(|(var_name, _)| var_name == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<R: RowValue> LinearProgram<R> {
+    pub fn new(sense: ObjectiveSense) -> Self {
+        Self {
+            sense,
+            variables: vec![],
+            bounds: vec![],
+            objective: vec![],
+            constraints: vec![],
+        }
+    }
+
+    /// Adds a non-negative variable, or returns the existing one if `name`
+    /// is taken.
+    pub fn add_variable(&mut self, name: &str) -> Variable {
+        if let Some(var) = self.variable(name) {
+            return var;
+        }
+        self.variables.push(name.to_string());
+        self.bounds.push(VariableBounds::default());
+        self.variables.len() - 1
+    }
+
+    pub fn variable(&self, name: &str) -> Option<Variable> {
+        self.variables.iter().position(|var_name| var_name == name)
+    }
+
+    pub fn set_objective(&mut self, terms: Vec<Term<R>>) {
+        self.objective = terms;
+    }
+
+    pub fn add_constraint(
+        &mut self,
+        name: &str,
+        terms: Vec<Term<R>>,
+        operator: Operator,
+        bound: R,
+    ) -> usize {
+        self.constraints.push(NamedConstraint {
+            name: name.to_string(),
+            terms,
+            operator,
+            bound,
+            range: None,
+        });
+        self.constraints.len() - 1
+    }
+
+    pub fn set_lower_bound(&mut self, var: Variable, bound: Option<R>) {
+        self.bounds[var].lower = bound;
+    }
+
+    pub fn set_upper_bound(&mut self, var: Variable, bound: Option<R>) {
+        self.bounds[var].upper = bound;
+    }
+
+    /// Removes both bounds of `var`.
+    pub fn set_free(&mut self, var: Variable) {
+        self.bounds[var] = VariableBounds {
+            lower: None,
+            upper: None,
+        };
+    }
+
+    pub fn objective_coeffs(&self) -> Coefficients<R> {
+        dense_coefficients(&self.objective, self.variables.len())
+    }
+
+    pub fn functional_constraints(&self) -> Vec<Constraint<R>> {
+        self.constraints
+            .iter()
+            .map(|constraint| Constraint {
+                operator: constraint.operator.clone(),
+                coefficients: dense_coefficients(&constraint.terms, self.variables.len()),
+                bound: constraint.bound.clone(),
+            })
+            .collect()
+    }
+
+    /// The tableau problem over the non-negative columns of the lowered
+    /// variables. Rows follow `constraints`, then one row per ranged
+    /// constraint for the other side of its range, then one `"{name}.ub"`
+    /// row per variable bounded on both sides.
+    pub fn problem<O: ObjectiveValue<R>>(&self) -> Problem<R, O> {
+        let substitutions = self.substitutions();
+        let constraints: Vec<Constraint<R>> = self
+            .lowered_rows(&substitutions)
+            .iter()
+            .map(SparseConstraint::to_dense)
+            .collect();
+        Problem::with_sense(
+            self.sense,
+            &self.lowered_objective(&substitutions),
+            &constraints,
+        )
+    }
+
+    /// Like `problem`, with sparse rows that are never made dense.
+    pub fn sparse_problem<O: ObjectiveValue<R>>(&self) -> SparseProblem<R, O> {
+        let substitutions = self.substitutions();
+        SparseProblem::with_sense(
+            self.sense,
+            &self.lowered_objective(&substitutions),
+            &self.lowered_rows(&substitutions),
+        )
+        .expect("lowered rows only use the lowered columns")
+    }
+
+    /// Like `problem`, but variables bounded on both sides get an upper
+    /// bound on their column instead of a row.
+    pub fn bounded_problem<O: ObjectiveValue<R>>(&self) -> BoundedProblem<R, O> {
+        let substitutions = self.substitutions();
+        let column_count = column_count(&substitutions);
+        let mut upper_bounds = vec![None; column_count];
+        for (var, width) in self.bound_rows() {
+            upper_bounds[substitutions[var].columns[0].0] = Some(width);
+        }
+        let constraints: Vec<Constraint<R>> = self
+            .lowered_constraints(&substitutions)
+            .iter()
+            .map(SparseConstraint::to_dense)
+            .collect();
+        let problem = Problem::with_sense(
+            self.sense,
+            &self.lowered_objective(&substitutions),
+            &constraints,
+        );
+        BoundedProblem::new(problem, &upper_bounds)
+    }
+
+    pub fn solve<O: ObjectiveValue<R>>(
+        &self,
+        observer: &mut impl ProblemObserver<R, O>,
+    ) -> Solution<R, O> {
+        self.solution(primal::solve(self.problem(), observer))
+    }
+
+    /// Solves `bounded_problem` with the bounded-variable simplex.
+    pub fn solve_bounded<O: ObjectiveValue<R>>(
+        &self,
+        observer: &mut impl ProblemObserver<R, O>,
+    ) -> Solution<R, O> {
+        self.solution(bounded::solve(self.bounded_problem(), observer))
+    }
+
+    /// Solves `problem` with the revised simplex.
+    pub fn solve_revised<O: ObjectiveValue<R>>(&self) -> Solution<R, O> {
+        self.solution(revised::solve(self.problem()))
+    }
+
+    /// Solves `sparse_problem` with the sparse tableau solver.
+    pub fn solve_sparse<O: ObjectiveValue<R>>(&self) -> Solution<R, O> {
+        self.solution(sparse::solve(self.sparse_problem()))
+    }
+
+    pub fn solve_with<S: Strategy<R>>(
+        &self,
+        strategy: &S,
+        observer: &mut impl ProblemObserver<R, S::Objective>,
+    ) -> Solution<R, S::Objective> {
+        self.solution(strategy.solve(self.problem(), &SolveOptions::default(), observer))
+    }
+
+    /// Maps a result for `problem()` back onto the original variables and
+    /// row names.
+    pub fn solution<O: ObjectiveValue<R>, P>(
+        &self,
+        result: SolveResult<R, O, P>,
+    ) -> Solution<R, O> {
+        let substitutions = self.substitutions();
+        let (_, objective_constant) = lower_terms(
+            &self.objective,
+            &substitutions,
+            column_count(&substitutions),
+        );
+        let row_names = self.row_names();
+        Solution {
+            status: result.status,
+            objective_value: result.objective_value + objective_constant,
+            values: self
+                .variables
+                .iter()
+                .cloned()
+                .zip(substitutions.iter().map(|sub| sub.value(&result.point)))
+                .collect(),
+            infeasible_constraints: result
+                .infeasible_constraints
+                .iter()
+                .map(|&idx| row_names[idx].clone())
+                .collect(),
+        }
+    }
+
+    fn lowered_objective(&self, substitutions: &[Substitution<R>]) -> Coefficients<R> {
+        let (objective_coeffs, _) =
+            lower_terms(&self.objective, substitutions, column_count(substitutions));
+        objective_coeffs.to_dense()
+    }
+
+    /// The lowered constraints followed by the other side of each range.
+    fn lowered_constraints(&self, substitutions: &[Substitution<R>]) -> Vec<SparseConstraint<R>> {
+        let column_count = column_count(substitutions);
+        let bound_sides = self.constraints.iter().map(|constraint| {
+            (
+                constraint,
+                constraint.bound_operator(),
+                constraint.bound.clone(),
+            )
+        });
+        let range_sides = self.constraints.iter().filter_map(|constraint| {
+            let (operator, bound) = constraint.range_row()?;
+            Some((constraint, operator, bound))
+        });
+        bound_sides
+            .chain(range_sides)
+            .map(|(constraint, operator, bound)| {
+                let (coefficients, constant) =
+                    lower_terms(&constraint.terms, substitutions, column_count);
+                with_non_negative_bound(SparseConstraint {
+                    operator,
+                    coefficients,
+                    bound: bound + -constant,
+                })
+            })
+            .collect()
+    }
+
+    /// The name of each row of `problem`: ranged constraints name both of
+    /// their rows.
+    fn row_names(&self) -> Vec<String> {
+        let constraint_names = self
+            .constraints
+            .iter()
+            .map(|constraint| constraint.name.clone());
+        let range_names = self
+            .constraints
+            .iter()
+            .filter(|constraint| constraint.range.is_some())
+            .map(|constraint| constraint.name.clone());
+        let bound_names = self
+            .bound_rows()
+            .map(|(var, _)| format!("{}.ub", self.variables[var]));
+        constraint_names
+            .chain(range_names)
+            .chain(bound_names)
+            .collect()
+    }
+
+    /// The lowered constraints and ranges followed by the upper bound rows.
+    fn lowered_rows(&self, substitutions: &[Substitution<R>]) -> Vec<SparseConstraint<R>> {
+        let column_count = column_count(substitutions);
+        let mut constraints = self.lowered_constraints(substitutions);
+        for (var, width) in self.bound_rows() {
+            let column = substitutions[var].columns[0].0;
+            constraints.push(with_non_negative_bound(SparseConstraint {
+                operator: Operator::LESSTHANEQUAL,
+                coefficients: SparseCoefficients::from_entries(
+                    column_count,
+                    vec![(column, R::one())],
+                ),
+                bound: width,
+            }));
+        }
+        constraints
+    }
+
+    fn substitutions(&self) -> Vec<Substitution<R>> {
+        let mut next_column = 0;
+        self.bounds
+            .iter()
+            .map(|bounds| {
+                let substitution = match (&bounds.lower, &bounds.upper) {
+                    (Some(lower), _) => Substitution {
+                        offset: lower.clone(),
+                        columns: vec![(next_column, R::one())],
+                    },
+                    (None, Some(upper)) => Substitution {
+                        offset: upper.clone(),
+                        columns: vec![(next_column, -R::one())],
+                    },
+                    (None, None) => Substitution {
+                        offset: R::zero(),
+                        columns: vec![(next_column, R::one()), (next_column + 1, -R::one())],
+                    },
+                };
+                next_column += substitution.columns.len();
+                substitution
+            })
+            .collect()
+    }
+
+    /// The variables bounded on both sides, with the width of their range,
+    /// which bounds their shifted column.
+    fn bound_rows(&self) -> impl Iterator<Item = (Variable, R)> + '_ {
+        self.bounds.iter().enumerate().filter_map(|(var, bounds)| {
+            match (&bounds.lower, &bounds.upper) {
+                (Some(lower), Some(upper)) => Some((var, upper.clone() + -lower.clone())),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// A variable written as `offset + sum(sign * column)` over non-negative
+/// tableau columns.
+struct Substitution<R: RowValue> {
+    offset: R,
+    columns: Vec<(usize, R)>,
+}
+
+impl<R: RowValue> Substitution<R> {
+    fn value(&self, point: &[R]) -> R {
+        self.columns
+            .iter()
+            .fold(self.offset.clone(), |value, (column, sign)| {
+                value + sign.clone() * point[*column].clone()
+            })
+    }
+}
+
+/// The tableau starts from the slack basis, which needs every bound to be
+/// non-negative, so a row with a negative bound is negated.
+fn with_non_negative_bound<R: RowValue>(constraint: SparseConstraint<R>) -> SparseConstraint<R> {
+    if constraint.bound >= R::zero() {
+        return constraint;
+    }
+    let mut coefficients = constraint.coefficients;
+    for (_, coeff) in &mut coefficients.entries {
+        *coeff = -coeff.clone();
+    }
+    SparseConstraint {
+        operator: match constraint.operator {
+            Operator::LESSTHANEQUAL => Operator::GREATERTHANEQUAL,
+            Operator::GREATERTHANEQUAL => Operator::LESSTHANEQUAL,
+            Operator::EQUAL => Operator::EQUAL,
+        },
+        coefficients,
+        bound: -constraint.bound,
+    }
+}
+
+fn column_count<R: RowValue>(substitutions: &[Substitution<R>]) -> usize {
+    substitutions.iter().map(|sub| sub.columns.len()).sum()
+}
+
+/// Coefficients over the lowered columns, and the constant left over from
+/// the variables' offsets.
+fn lower_terms<R: RowValue>(
+    terms: &[Term<R>],
+    substitutions: &[Substitution<R>],
+    column_count: usize,
+) -> (SparseCoefficients<R>, R) {
+    let mut entries = vec![];
+    let mut constant = R::zero();
+    for (var, coeff) in terms {
+        let substitution = &substitutions[*var];
+        for (column, sign) in &substitution.columns {
+            entries.push((*column, coeff.clone() * sign.clone()));
+        }
+        constant = constant + coeff.clone() * substitution.offset.clone();
+    }
+    (
+        SparseCoefficients::from_entries(column_count, entries),
+        constant,
+    )
+}
+
+fn dense_coefficients<R: RowValue>(terms: &[Term<R>], var_count: usize) -> Coefficients<R> {
+    let mut coeffs = vec![R::zero(); var_count];
+    for (var, coeff) in terms {
+        coeffs[*var] = coeffs[*var].clone() + coeff.clone();
+    }
+    coeffs
+}
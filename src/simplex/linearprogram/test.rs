@@ -0,0 +1,293 @@
+use crate::simplex::linearprogram::{self as sut};
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::{EmptyObserver, Problem, SolveStatus};
+use crate::simplex::test::{frac, lower_bound_constraint, upper_bound_constraint};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{ObjectiveSense, Operator};
+
+fn make_production_program() -> sut::LinearProgram<Value> {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let chairs = program.add_variable("chairs");
+    let tables = program.add_variable("tables");
+    program.set_objective(vec![(chairs, frac(1, 1)), (tables, frac(2, 1))]);
+    program.add_constraint(
+        "wood",
+        vec![(chairs, frac(1, 1)), (tables, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(4, 1),
+    );
+    program.add_constraint(
+        "labour",
+        vec![(tables, frac(3, 1)), (chairs, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(8, 1),
+    );
+    program
+}
+
+#[test]
+fn adds_named_variables() {
+    let mut program = sut::LinearProgram::<Value>::new(ObjectiveSense::Maximize);
+    assert_eq!(0, program.add_variable("x"));
+    assert_eq!(1, program.add_variable("y"));
+    assert_eq!(Some(1), program.variable("y"));
+    assert_eq!(None, program.variable("z"));
+}
+
+#[test]
+fn reuses_variable_with_existing_name() {
+    let mut program = sut::LinearProgram::<Value>::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.add_variable("y");
+    assert_eq!(x, program.add_variable("x"));
+    assert_eq!(vec!["x".to_string(), "y".to_string()], program.variables);
+}
+
+#[test]
+fn lowers_objective_to_dense_coefficients() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.add_variable("y");
+    let z = program.add_variable("z");
+    program.set_objective(vec![(z, frac(3, 1)), (x, frac(1, 1)), (z, frac(1, 1))]);
+    assert_eq!(
+        vec![frac(1, 1), value::zero(), frac(4, 1)],
+        program.objective_coeffs()
+    );
+}
+
+#[test]
+fn lowers_sparse_constraints_to_dense_constraints() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    let y = program.add_variable("y");
+    program.add_constraint(
+        "upper",
+        vec![(y, frac(2, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(6, 1),
+    );
+    program.add_constraint(
+        "lower",
+        vec![(x, frac(1, 1)), (y, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(1, 1),
+    );
+    let expected_constraints = vec![
+        upper_bound_constraint(vec![value::zero(), frac(2, 1)], frac(6, 1)),
+        lower_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(1, 1)),
+    ];
+    assert_eq!(expected_constraints, program.functional_constraints());
+}
+
+#[test]
+fn solves_and_maps_values_to_names() {
+    let program = make_production_program();
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(MObjectiveValue::from(frac(6, 1)), solution.objective_value);
+    assert_eq!(
+        vec![
+            ("chairs".to_string(), frac(2, 1)),
+            ("tables".to_string(), frac(2, 1))
+        ],
+        solution.values
+    );
+    assert_eq!(Some(&frac(2, 1)), solution.value("tables"));
+    assert_eq!(None, solution.value("stools"));
+}
+
+#[test]
+fn solves_minimization_program() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Minimize);
+    let x = program.add_variable("x");
+    let y = program.add_variable("y");
+    program.set_objective(vec![(x, frac(2, 1)), (y, frac(3, 1))]);
+    program.add_constraint(
+        "first",
+        vec![(x, frac(1, 1)), (y, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(4, 1),
+    );
+    program.add_constraint(
+        "second",
+        vec![(x, frac(1, 1)), (y, frac(3, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(6, 1),
+    );
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(MObjectiveValue::from(frac(9, 1)), solution.objective_value);
+    assert_eq!(Some(&frac(3, 1)), solution.value("x"));
+    assert_eq!(Some(&frac(1, 1)), solution.value("y"));
+}
+
+#[test]
+fn names_infeasible_constraints() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_objective(vec![(x, frac(1, 1))]);
+    program.add_constraint(
+        "cap",
+        vec![(x, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(2, 1),
+    );
+    program.add_constraint(
+        "demand",
+        vec![(x, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(4, 1),
+    );
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, solution.status);
+    assert_eq!(vec!["demand".to_string()], solution.infeasible_constraints);
+}
+
+#[test]
+fn solves_with_free_variable() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Minimize);
+    let x = program.add_variable("x");
+    program.set_free(x);
+    program.set_objective(vec![(x, frac(1, 1))]);
+    program.add_constraint(
+        "floor",
+        vec![(x, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        -frac(3, 1),
+    );
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(Some(&-frac(3, 1)), solution.value("x"));
+    assert_eq!(MObjectiveValue::from(-frac(3, 1)), solution.objective_value);
+}
+
+#[test]
+fn solves_with_shifted_and_mirrored_bounds() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    let y = program.add_variable("y");
+    let z = program.add_variable("z");
+    program.set_lower_bound(x, Some(frac(1, 1)));
+    program.set_upper_bound(x, Some(frac(3, 1)));
+    program.set_lower_bound(y, Some(-frac(2, 1)));
+    program.set_upper_bound(y, Some(frac(2, 1)));
+    program.set_lower_bound(z, None);
+    program.set_upper_bound(z, Some(-frac(1, 1)));
+    program.set_objective(vec![(x, frac(2, 1)), (y, frac(1, 1)), (z, frac(1, 1))]);
+    program.add_constraint(
+        "total",
+        vec![(x, frac(1, 1)), (y, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(4, 1),
+    );
+    let problem: Problem<Value, MObjectiveValue> = program.problem();
+    assert_eq!(3, problem.rows.len());
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(
+        vec![
+            ("x".to_string(), frac(3, 1)),
+            ("y".to_string(), frac(1, 1)),
+            ("z".to_string(), -frac(1, 1))
+        ],
+        solution.values
+    );
+    assert_eq!(MObjectiveValue::from(frac(6, 1)), solution.objective_value);
+}
+
+#[test]
+fn names_infeasible_bound_rows() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_lower_bound(x, Some(frac(2, 1)));
+    program.set_upper_bound(x, Some(frac(1, 1)));
+    program.set_objective(vec![(x, frac(1, 1))]);
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, solution.status);
+    assert_eq!(vec!["x.ub".to_string()], solution.infeasible_constraints);
+}
+
+#[test]
+fn lowers_ranges_to_a_second_row() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_objective(vec![(x, frac(1, 1))]);
+    let mix = program.add_constraint("mix", vec![(x, frac(1, 1))], Operator::EQUAL, frac(5, 1));
+    program.constraints[mix].range = Some(-frac(2, 1));
+    let problem: Problem<Value, MObjectiveValue> = program.problem();
+    assert_eq!(
+        Problem::new(
+            &vec![frac(1, 1)],
+            &[
+                upper_bound_constraint(vec![frac(1, 1)], frac(5, 1)),
+                lower_bound_constraint(vec![frac(1, 1)], frac(3, 1)),
+            ],
+        ),
+        problem
+    );
+}
+
+#[test]
+fn names_infeasible_range_rows_after_their_constraint() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_upper_bound(x, Some(frac(1, 1)));
+    program.set_objective(vec![(x, frac(1, 1))]);
+    let cap = program.add_constraint(
+        "cap",
+        vec![(x, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(5, 1),
+    );
+    program.constraints[cap].range = Some(frac(2, 1));
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, solution.status);
+    assert_eq!(vec!["cap".to_string()], solution.infeasible_constraints);
+}
+
+#[test]
+fn solves_bounded_variables_without_bound_rows() {
+    let mut program = sut::LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    let y = program.add_variable("y");
+    program.set_lower_bound(x, Some(frac(1, 1)));
+    program.set_upper_bound(x, Some(frac(3, 1)));
+    program.set_lower_bound(y, Some(-frac(2, 1)));
+    program.set_upper_bound(y, Some(frac(2, 1)));
+    program.set_objective(vec![(x, frac(2, 1)), (y, frac(1, 1))]);
+    program.add_constraint(
+        "total",
+        vec![(x, frac(1, 1)), (y, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(4, 1),
+    );
+    let problem = program.bounded_problem::<MObjectiveValue>();
+    assert_eq!(1, problem.problem.rows.len());
+    assert_eq!(
+        vec![Some(frac(2, 1)), Some(frac(4, 1)), None],
+        problem.upper_bounds
+    );
+    let bounded = program.solve_bounded::<MObjectiveValue>(&mut EmptyObserver::new());
+    assert_eq!(program.solve(&mut EmptyObserver::new()), bounded);
+    assert_eq!(Some(&frac(3, 1)), bounded.value("x"));
+    assert_eq!(Some(&frac(1, 1)), bounded.value("y"));
+}
+
+#[test]
+fn solves_with_revised_simplex() {
+    let program = make_production_program();
+    let solution = program.solve_revised::<MObjectiveValue>();
+    assert_eq!(program.solve(&mut EmptyObserver::new()), solution);
+}
+
+#[test]
+fn solves_with_sparse_rows() {
+    let mut program = make_production_program();
+    let chairs = program.variable("chairs").unwrap();
+    program.set_lower_bound(chairs, Some(frac(1, 1)));
+    program.set_upper_bound(chairs, Some(frac(3, 1)));
+    let problem = program.sparse_problem::<MObjectiveValue>();
+    assert_eq!(3, problem.rows.len());
+    let solution = program.solve_sparse::<MObjectiveValue>();
+    assert_eq!(program.solve(&mut EmptyObserver::new()), solution);
+}
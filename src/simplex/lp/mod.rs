@@ -0,0 +1,543 @@
+#[cfg(test)]
+mod test;
+
+use std::fmt::Display;
+
+use crate::simplex::linearprogram::{LinearProgram, Term};
+use crate::simplex::mip::MixedIntegerProgram;
+use crate::simplex::rowvalue::{self, RowValue};
+use crate::simplex::{ObjectiveSense, Operator, Variable};
+
+/// An error in an LP file, located by 1-based line and column.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(PartialEq, Debug, Clone)]
+enum TokenKind<R: RowValue> {
+    Number(R),
+    Name(String),
+    Plus,
+    Minus,
+    Colon,
+    Relation(Operator),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Token<R: RowValue> {
+    kind: TokenKind<R>,
+    line: usize,
+    column: usize,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Section {
+    Objective,
+    Constraints,
+    Bounds,
+    General,
+    Binary,
+    End,
+    Unsupported,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum BoundValue<R: RowValue> {
+    Finite(R),
+    PlusInfinity,
+    MinusInfinity,
+}
+
+/// Parses a model in CPLEX LP format. Supports the objective, constraints
+/// and bounds sections; variables default to a lower bound of zero.
+/// Numbers are read as `R`, so a `BigValue` program takes any literal
+/// exactly.
+pub fn parse<R: RowValue>(input: &str) -> Result<LinearProgram<R>, ParseError> {
+    parse_model(input, false).map(|(program, _)| program)
+}
+
+/// Like `parse`, and also reads the general and binary sections, which
+/// list the integer and binary variables.
+pub fn parse_mip(input: &str) -> Result<MixedIntegerProgram, ParseError> {
+    let (program, integer_variables) = parse_model(input, true)?;
+    let mut mip = MixedIntegerProgram::new(program);
+    for var in integer_variables {
+        mip.set_integer(var);
+    }
+    Ok(mip)
+}
+
+/// The program and the variables listed in its integer sections. Binary
+/// variables get their bounds as their section is read, so a later bounds
+/// section still overrides them.
+fn parse_model<R: RowValue>(
+    input: &str,
+    integers: bool,
+) -> Result<(LinearProgram<R>, Vec<Variable>), ParseError> {
+    let mut sense = None;
+    let mut sections: Vec<(Section, Vec<Token<R>>)> = vec![];
+    for (line_idx, raw_line) in input.lines().enumerate() {
+        let line_no = line_idx + 1;
+        let line = raw_line.split('\\').next().unwrap_or("");
+        let mut rest_offset = 0;
+        if let Some((section, header_sense, offset)) = section_header(line) {
+            match section {
+                Section::End => break,
+                Section::General | Section::Binary if !integers => {
+                    return Err(error_at(
+                        line_no,
+                        1,
+                        "integer sections are only read by parse_mip",
+                    ));
+                }
+                Section::Unsupported => {
+                    return Err(error_at(line_no, 1, "unsupported section"));
+                }
+                _ => {}
+            }
+            if header_sense.is_some() {
+                sense = header_sense;
+            }
+            sections.push((section, vec![]));
+            rest_offset = offset;
+        }
+        let tokens = tokenize(line, line_no, rest_offset)?;
+        if tokens.is_empty() {
+            continue;
+        }
+        let Some((_, section_tokens)) = sections.last_mut() else {
+            return Err(error_at(
+                tokens[0].line,
+                tokens[0].column,
+                "expected a Maximize or Minimize section",
+            ));
+        };
+        section_tokens.extend(tokens);
+    }
+    let Some(sense) = sense else {
+        return Err(error_at(1, 1, "missing Maximize or Minimize section"));
+    };
+    let mut program = LinearProgram::new(sense);
+    let mut integer_variables = vec![];
+    for (section, tokens) in &sections {
+        let mut parser = Parser { tokens, pos: 0 };
+        match section {
+            Section::Objective => parser.objective(&mut program)?,
+            Section::Constraints => parser.constraints(&mut program)?,
+            Section::Bounds => parser.bounds(&mut program)?,
+            Section::General => integer_variables.extend(parser.variables(&mut program)?),
+            Section::Binary => {
+                for var in parser.variables(&mut program)? {
+                    program.set_lower_bound(var, Some(R::zero()));
+                    program.set_upper_bound(var, Some(R::one()));
+                    integer_variables.push(var);
+                }
+            }
+            Section::End | Section::Unsupported => {}
+        }
+    }
+    Ok((program, integer_variables))
+}
+
+fn error_at(line: usize, column: usize, message: &str) -> ParseError {
+    ParseError {
+        line,
+        column,
+        message: message.to_string(),
+    }
+}
+
+fn section_header(line: &str) -> Option<(Section, Option<ObjectiveSense>, usize)> {
+    let trimmed = line.trim_start();
+    let indent = line.len() - trimmed.len();
+    let lower = trimmed.to_lowercase();
+    let words: Vec<&str> = lower.split_whitespace().collect();
+    let first = *words.first()?;
+    let (section, sense, word_count) = match first {
+        "maximize" | "maximise" | "maximum" | "max" => {
+            (Section::Objective, Some(ObjectiveSense::Maximize), 1)
+        }
+        "minimize" | "minimise" | "minimum" | "min" => {
+            (Section::Objective, Some(ObjectiveSense::Minimize), 1)
+        }
+        "subject" | "such" if words.len() > 1 && matches!(words[1], "to" | "that") => {
+            (Section::Constraints, None, 2)
+        }
+        "st" | "s.t." | "st." => (Section::Constraints, None, 1),
+        "bounds" | "bound" => (Section::Bounds, None, 1),
+        "general" | "generals" | "gen" | "integer" | "integers" => (Section::General, None, 1),
+        "binary" | "binaries" | "bin" => (Section::Binary, None, 1),
+        "semi-continuous" | "semis" | "semi" => (Section::Unsupported, None, 1),
+        "end" => (Section::End, None, 1),
+        _ => return None,
+    };
+    Some((section, sense, indent + header_len(trimmed, word_count)))
+}
+
+fn header_len(trimmed: &str, word_count: usize) -> usize {
+    let mut len = 0;
+    for _ in 0..word_count {
+        let rest = &trimmed[len..];
+        let word_start = rest.len() - rest.trim_start().len();
+        let word_len = rest[word_start..]
+            .find(char::is_whitespace)
+            .unwrap_or(rest.len() - word_start);
+        len += word_start + word_len;
+    }
+    len
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || "!\"#$%&()/,.;?@_`'{}|~".contains(c)
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start(c) || c.is_ascii_digit()
+}
+
+fn tokenize<R: RowValue>(
+    line: &str,
+    line_no: usize,
+    offset: usize,
+) -> Result<Vec<Token<R>>, ParseError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = vec![];
+    let mut idx = line[..offset].chars().count();
+    while idx < chars.len() {
+        let c = chars[idx];
+        let column = idx + 1;
+        let token = |kind| Token {
+            kind,
+            line: line_no,
+            column,
+        };
+        if c.is_whitespace() {
+            idx += 1;
+        } else if c.is_ascii_digit() || (c == '.' && next_is_digit(&chars, idx)) {
+            let len = number_len(&chars[idx..]);
+            let text: String = chars[idx..idx + len].iter().collect();
+            let Some(number) = rowvalue::parse_decimal(&text) else {
+                return Err(error_at(line_no, column, "invalid number"));
+            };
+            tokens.push(token(TokenKind::Number(number)));
+            idx += len;
+        } else if is_name_start(c) {
+            let len = chars[idx..]
+                .iter()
+                .take_while(|c| is_name_char(**c))
+                .count();
+            tokens.push(token(TokenKind::Name(
+                chars[idx..idx + len].iter().collect(),
+            )));
+            idx += len;
+        } else {
+            let next = chars.get(idx + 1).copied();
+            let (kind, len) = match (c, next) {
+                ('+', _) => (TokenKind::Plus, 1),
+                ('-', _) => (TokenKind::Minus, 1),
+                (':', _) => (TokenKind::Colon, 1),
+                ('<', Some('=')) | ('=', Some('<')) => {
+                    (TokenKind::Relation(Operator::LESSTHANEQUAL), 2)
+                }
+                ('>', Some('=')) | ('=', Some('>')) => {
+                    (TokenKind::Relation(Operator::GREATERTHANEQUAL), 2)
+                }
+                ('<', _) => (TokenKind::Relation(Operator::LESSTHANEQUAL), 1),
+                ('>', _) => (TokenKind::Relation(Operator::GREATERTHANEQUAL), 1),
+                ('=', _) => (TokenKind::Relation(Operator::EQUAL), 1),
+                _ => return Err(error_at(line_no, column, "unexpected character")),
+            };
+            tokens.push(token(kind));
+            idx += len;
+        }
+    }
+    Ok(tokens)
+}
+
+fn next_is_digit(chars: &[char], idx: usize) -> bool {
+    chars.get(idx + 1).is_some_and(|c| c.is_ascii_digit())
+}
+
+fn number_len(chars: &[char]) -> usize {
+    let mut len = chars
+        .iter()
+        .take_while(|c| c.is_ascii_digit() || **c == '.')
+        .count();
+    if matches!(chars.get(len), Some('e' | 'E')) {
+        let sign_len = usize::from(matches!(chars.get(len + 1), Some('+' | '-')));
+        let exponent_len = chars[(len + 1 + sign_len).min(chars.len())..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if exponent_len > 0 {
+            len += 1 + sign_len + exponent_len;
+        }
+    }
+    len
+}
+
+struct Parser<'a, R: RowValue> {
+    tokens: &'a [Token<R>],
+    pos: usize,
+}
+
+impl<R: RowValue> Parser<'_, R> {
+    fn peek(&self) -> Option<&TokenKind<R>> {
+        self.tokens.get(self.pos).map(|token| &token.kind)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&TokenKind<R>> {
+        self.tokens.get(self.pos + offset).map(|token| &token.kind)
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        match (self.tokens.get(self.pos), self.tokens.last()) {
+            (Some(token), _) => error_at(token.line, token.column, message),
+            (None, Some(last)) => error_at(last.line, last.column + 1, message),
+            (None, None) => error_at(1, 1, message),
+        }
+    }
+
+    fn label(&mut self) -> Option<String> {
+        match (self.peek(), self.peek_at(1)) {
+            (Some(TokenKind::Name(name)), Some(TokenKind::Colon)) => {
+                let name = name.clone();
+                self.pos += 2;
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    fn sign(&mut self) -> Option<bool> {
+        let mut negative = None;
+        while let Some(kind @ (TokenKind::Plus | TokenKind::Minus)) = self.peek() {
+            let flip = *kind == TokenKind::Minus;
+            negative = Some(negative.unwrap_or(false) ^ flip);
+            self.pos += 1;
+        }
+        negative
+    }
+
+    fn expression(&mut self, program: &mut LinearProgram<R>) -> Result<Vec<Term<R>>, ParseError> {
+        let mut terms = vec![];
+        loop {
+            let sign_start = self.pos;
+            let negative = self.sign();
+            if !terms.is_empty() && negative.is_none() {
+                return Ok(terms);
+            }
+            let coefficient = match self.peek() {
+                Some(TokenKind::Number(number)) => {
+                    let number = number.clone();
+                    self.pos += 1;
+                    if !matches!(self.peek(), Some(TokenKind::Name(_))) {
+                        return Err(self.error("expected a variable name"));
+                    }
+                    number
+                }
+                Some(TokenKind::Name(_)) => R::one(),
+                _ if negative.is_some() => return Err(self.error("expected a term")),
+                _ => {
+                    self.pos = sign_start;
+                    return Ok(terms);
+                }
+            };
+            let Some(TokenKind::Name(name)) = self.peek() else {
+                unreachable!()
+            };
+            let var = program.add_variable(name);
+            self.pos += 1;
+            let coefficient = if negative == Some(true) {
+                -coefficient
+            } else {
+                coefficient
+            };
+            terms.push((var, coefficient));
+        }
+    }
+
+    fn objective(&mut self, program: &mut LinearProgram<R>) -> Result<(), ParseError> {
+        self.label();
+        let terms = self.expression(program)?;
+        if self.peek().is_some() {
+            return Err(self.error("unexpected token in objective"));
+        }
+        program.set_objective(terms);
+        Ok(())
+    }
+
+    fn constraints(&mut self, program: &mut LinearProgram<R>) -> Result<(), ParseError> {
+        while self.peek().is_some() {
+            let name = self
+                .label()
+                .unwrap_or_else(|| format!("R{}", program.constraints.len() + 1));
+            let terms = self.expression(program)?;
+            if terms.is_empty() {
+                return Err(self.error("expected a constraint expression"));
+            }
+            let Some(TokenKind::Relation(operator)) = self.peek() else {
+                return Err(self.error("expected <=, >= or ="));
+            };
+            let operator = operator.clone();
+            self.pos += 1;
+            let negative = self.sign() == Some(true);
+            let Some(TokenKind::Number(bound)) = self.peek() else {
+                return Err(self.error("expected a right-hand side number"));
+            };
+            let bound = if negative {
+                -bound.clone()
+            } else {
+                bound.clone()
+            };
+            self.pos += 1;
+            program.add_constraint(&name, terms, operator, bound);
+        }
+        Ok(())
+    }
+
+    fn bound_value(&mut self) -> Result<BoundValue<R>, ParseError> {
+        let negative = self.sign() == Some(true);
+        let value = match self.peek() {
+            Some(TokenKind::Number(number)) => BoundValue::Finite(if negative {
+                -number.clone()
+            } else {
+                number.clone()
+            }),
+            Some(TokenKind::Name(name)) if is_infinity(name) => {
+                if negative {
+                    BoundValue::MinusInfinity
+                } else {
+                    BoundValue::PlusInfinity
+                }
+            }
+            _ => return Err(self.error("expected a bound value")),
+        };
+        self.pos += 1;
+        Ok(value)
+    }
+
+    fn relation(&mut self) -> Result<Operator, ParseError> {
+        let Some(TokenKind::Relation(operator)) = self.peek() else {
+            return Err(self.error("expected <=, >= or ="));
+        };
+        let operator = operator.clone();
+        self.pos += 1;
+        Ok(operator)
+    }
+
+    fn bound_variable(&mut self, program: &mut LinearProgram<R>) -> Result<Variable, ParseError> {
+        match self.peek() {
+            Some(TokenKind::Name(name)) if !is_infinity(name) => {
+                let var = program.add_variable(name);
+                self.pos += 1;
+                Ok(var)
+            }
+            _ => Err(self.error("expected a variable name")),
+        }
+    }
+
+    /// The variables listed in a general or binary section.
+    fn variables(&mut self, program: &mut LinearProgram<R>) -> Result<Vec<Variable>, ParseError> {
+        let mut vars = vec![];
+        while self.peek().is_some() {
+            vars.push(self.bound_variable(program)?);
+        }
+        Ok(vars)
+    }
+
+    fn bounds(&mut self, program: &mut LinearProgram<R>) -> Result<(), ParseError> {
+        while self.peek().is_some() {
+            let start = self.pos;
+            let value_first = match self.peek() {
+                Some(TokenKind::Name(name)) => is_infinity(name),
+                _ => true,
+            };
+            if value_first {
+                let value = self.bound_value()?;
+                let operator = self.relation()?;
+                let var = self.bound_variable(program)?;
+                self.apply_bound(program, var, flip(operator), value, start)?;
+                if let Some(TokenKind::Relation(_)) = self.peek() {
+                    let operator_start = self.pos;
+                    let operator = self.relation()?;
+                    let value = self.bound_value()?;
+                    self.apply_bound(program, var, operator, value, operator_start)?;
+                }
+            } else {
+                let var = self.bound_variable(program)?;
+                if let Some(TokenKind::Name(word)) = self.peek()
+                    && word.eq_ignore_ascii_case("free")
+                {
+                    self.pos += 1;
+                    program.set_free(var);
+                    continue;
+                }
+                let operator = self.relation()?;
+                let value = self.bound_value()?;
+                self.apply_bound(program, var, operator, value, start)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_bound(
+        &mut self,
+        program: &mut LinearProgram<R>,
+        var: Variable,
+        operator: Operator,
+        value: BoundValue<R>,
+        start: usize,
+    ) -> Result<(), ParseError> {
+        match (operator, value) {
+            (Operator::LESSTHANEQUAL, BoundValue::PlusInfinity) => {
+                program.set_upper_bound(var, None);
+            }
+            (Operator::LESSTHANEQUAL, BoundValue::Finite(bound)) => {
+                program.set_upper_bound(var, Some(bound));
+            }
+            (Operator::GREATERTHANEQUAL, BoundValue::MinusInfinity) => {
+                program.set_lower_bound(var, None);
+            }
+            (Operator::GREATERTHANEQUAL, BoundValue::Finite(bound)) => {
+                program.set_lower_bound(var, Some(bound));
+            }
+            (Operator::EQUAL, BoundValue::Finite(bound)) => {
+                program.set_lower_bound(var, Some(bound.clone()));
+                program.set_upper_bound(var, Some(bound));
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error("invalid bound"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_infinity(name: &str) -> bool {
+    name.eq_ignore_ascii_case("inf") || name.eq_ignore_ascii_case("infinity")
+}
+
+fn flip(operator: Operator) -> Operator {
+    match operator {
+        Operator::LESSTHANEQUAL => Operator::GREATERTHANEQUAL,
+        Operator::GREATERTHANEQUAL => Operator::LESSTHANEQUAL,
+        Operator::EQUAL => Operator::EQUAL,
+    }
+}
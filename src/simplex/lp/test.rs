@@ -0,0 +1,313 @@
+use fraction::BigUint;
+
+use crate::simplex::bigvalue::BigValue;
+use crate::simplex::float::Float;
+use crate::simplex::linearprogram::VariableBounds;
+use crate::simplex::lp::{self as sut};
+use crate::simplex::tabular::SolveStatus;
+use crate::simplex::test::frac;
+use crate::simplex::value::{self, Value};
+use crate::simplex::{ObjectiveSense, Operator};
+
+fn parse_error(input: &str) -> sut::ParseError {
+    sut::parse::<Value>(input).unwrap_err()
+}
+
+#[test]
+fn parses_objective_and_constraints() {
+    let program = sut::parse::<Value>(
+        "\\ production model
+Maximize
+ obj: 3 x + 2 y
+Subject To
+ wood: x + y <= 4
+ labour: x + 3 y >= 2
+ -x + y = 1
+End
+",
+    )
+    .unwrap();
+    assert_eq!(ObjectiveSense::Maximize, program.sense);
+    assert_eq!(vec!["x".to_string(), "y".to_string()], program.variables);
+    assert_eq!(vec![frac(3, 1), frac(2, 1)], program.objective_coeffs());
+    let constraints = program.functional_constraints();
+    assert_eq!(3, constraints.len());
+    assert_eq!(Operator::LESSTHANEQUAL, constraints[0].operator);
+    assert_eq!(vec![frac(1, 1), frac(3, 1)], constraints[1].coefficients);
+    assert_eq!(Operator::GREATERTHANEQUAL, constraints[1].operator);
+    assert_eq!(vec![-frac(1, 1), frac(1, 1)], constraints[2].coefficients);
+    assert_eq!(Operator::EQUAL, constraints[2].operator);
+    assert_eq!(frac(1, 1), constraints[2].bound);
+    assert_eq!("wood", program.constraints[0].name);
+    assert_eq!("labour", program.constraints[1].name);
+    assert_eq!("R3", program.constraints[2].name);
+}
+
+#[test]
+fn parses_minimize_with_keyword_variants() {
+    let program = sut::parse::<Value>("minimise cost: 2x + 3y\nst\n x + y >= 4\nend\n").unwrap();
+    assert_eq!(ObjectiveSense::Minimize, program.sense);
+    assert_eq!(vec![frac(2, 1), frac(3, 1)], program.objective_coeffs());
+    assert_eq!(frac(4, 1), program.constraints[0].bound);
+}
+
+#[test]
+fn parses_expressions_across_lines() {
+    let program = sut::parse::<Value>(
+        "max
+ 2 a
+ + 3 b
+s.t.
+ c1: a
+   + b
+   <= 10
+",
+    )
+    .unwrap();
+    assert_eq!(vec![frac(2, 1), frac(3, 1)], program.objective_coeffs());
+    assert_eq!(
+        vec![frac(1, 1), frac(1, 1)],
+        program.functional_constraints()[0].coefficients
+    );
+}
+
+#[test]
+fn parses_decimal_and_exponent_numbers() {
+    let program = sut::parse::<Value>("max 0.25 x + 1.5e2 y - 2E-1 z\n").unwrap();
+    assert_eq!(
+        vec![frac(1, 4), frac(150, 1), -frac(1, 5)],
+        program.objective_coeffs()
+    );
+}
+
+#[test]
+fn parses_long_literals_exactly_as_big_values() {
+    let input = "max 1.2345678901234567890123 x\n";
+    let program = sut::parse::<BigValue>(input).unwrap();
+    assert_eq!(
+        vec![BigValue::new(
+            BigUint::from(12345678901234567890123u128),
+            BigUint::from(10u32).pow(22)
+        )],
+        program.objective_coeffs()
+    );
+    let error = sut::parse::<Value>(input).unwrap_err();
+    assert_eq!(
+        (1, 5, "invalid number"),
+        (error.line, error.column, error.message.as_str())
+    );
+}
+
+#[test]
+fn parses_numbers_as_floats() {
+    let program = sut::parse::<Float>("max 0.1 x - 2.5e-1 y\n").unwrap();
+    assert_eq!(
+        vec![Float::new(0.1), Float::new(-0.25)],
+        program.objective_coeffs()
+    );
+}
+
+#[test]
+fn parses_relation_variants() {
+    let program =
+        sut::parse::<Value>("max x\nst\n x =< 1\n x => 0\n x < 2\n x > 0\n x = 1\n").unwrap();
+    let operators: Vec<Operator> = program
+        .constraints
+        .iter()
+        .map(|constraint| constraint.operator.clone())
+        .collect();
+    assert_eq!(
+        vec![
+            Operator::LESSTHANEQUAL,
+            Operator::GREATERTHANEQUAL,
+            Operator::LESSTHANEQUAL,
+            Operator::GREATERTHANEQUAL,
+            Operator::EQUAL
+        ],
+        operators
+    );
+}
+
+#[test]
+fn parses_negative_right_hand_side() {
+    let program = sut::parse::<Value>("max x\nst\n x - y >= -3\n").unwrap();
+    assert_eq!(-frac(3, 1), program.constraints[0].bound);
+}
+
+#[test]
+fn parses_variable_bounds() {
+    let program = sut::parse::<Value>(
+        "max x + y + z
+st
+ c: x + y + z <= 10
+bounds
+ x <= 4
+ 1 <= y <= 5
+ z >= 0
+ z <= inf
+end
+",
+    )
+    .unwrap();
+    assert_eq!(1, program.constraints.len());
+    assert_eq!(
+        vec![
+            VariableBounds {
+                lower: Some(value::zero()),
+                upper: Some(frac(4, 1))
+            },
+            VariableBounds {
+                lower: Some(frac(1, 1)),
+                upper: Some(frac(5, 1))
+            },
+            VariableBounds::default(),
+        ],
+        program.bounds
+    );
+}
+
+#[test]
+fn parses_fixed_bound() {
+    let program = sut::parse::<Value>("max x\nbounds\n x = 3\n").unwrap();
+    assert!(program.constraints.is_empty());
+    assert_eq!(
+        VariableBounds {
+            lower: Some(frac(3, 1)),
+            upper: Some(frac(3, 1))
+        },
+        program.bounds[0]
+    );
+}
+
+#[test]
+fn parses_empty_objective() {
+    let program = sut::parse::<Value>("minimize\nst\n x >= 1\n").unwrap();
+    assert_eq!(vec![value::zero()], program.objective_coeffs());
+}
+
+#[test]
+fn reports_missing_objective_section() {
+    let error = parse_error("st\n x <= 1\n");
+    assert_eq!((1, 1), (error.line, error.column));
+}
+
+#[test]
+fn reports_missing_relation_with_position() {
+    let error = parse_error("max x\nst\n c1: x + y 4\n");
+    assert_eq!(
+        sut::ParseError {
+            line: 3,
+            column: 12,
+            message: "expected <=, >= or =".to_string()
+        },
+        error
+    );
+    assert_eq!("line 3, column 12: expected <=, >= or =", error.to_string());
+}
+
+#[test]
+fn reports_missing_right_hand_side_at_end_of_input() {
+    let error = parse_error("max x\nst\n x <=\n");
+    assert_eq!((3, 5), (error.line, error.column));
+}
+
+#[test]
+fn reports_unexpected_character() {
+    let error = parse_error("max x * y\n");
+    assert_eq!((1, 7), (error.line, error.column));
+}
+
+#[test]
+fn reports_coefficient_without_variable() {
+    let error = parse_error("max x\nst\n x + 3 <= 5\n");
+    assert_eq!((3, 8), (error.line, error.column));
+    assert_eq!("expected a variable name", error.message);
+}
+
+#[test]
+fn parses_free_and_negative_bounds() {
+    let program =
+        sut::parse::<Value>("max x + y + z\nbounds\n  x free\n -2 <= y <= 4\n -inf <= z <= -1\n")
+            .unwrap();
+    assert_eq!(
+        vec![
+            VariableBounds {
+                lower: None,
+                upper: None
+            },
+            VariableBounds {
+                lower: Some(-frac(2, 1)),
+                upper: Some(frac(4, 1))
+            },
+            VariableBounds {
+                lower: None,
+                upper: Some(-frac(1, 1))
+            },
+        ],
+        program.bounds
+    );
+}
+
+#[test]
+fn reports_invalid_bound() {
+    let error = parse_error("max x\nbounds\n x >= inf\n");
+    assert_eq!((3, 2), (error.line, error.column));
+    assert_eq!("invalid bound", error.message);
+}
+
+#[test]
+fn reports_integer_sections_as_unsupported() {
+    let error = parse_error("max x\nst\n x <= 4\ngeneral\n x\nend\n");
+    assert_eq!((4, 1), (error.line, error.column));
+    assert_eq!("integer sections are only read by parse_mip", error.message);
+}
+
+#[test]
+fn parses_general_and_binary_sections() {
+    let mip = sut::parse_mip(
+        "max 2x + 3y + z\nst\n c1: 2x + 2y + z <= 7\nbounds\n x <= 10\ngeneral\n x\nbinary\n y z\nend\n",
+    )
+    .unwrap();
+    assert_eq!(vec![0, 1, 2], mip.integer_variables);
+    assert_eq!(
+        VariableBounds {
+            lower: Some(value::zero()),
+            upper: Some(frac(10, 1))
+        },
+        mip.program.bounds[0]
+    );
+    assert_eq!(
+        VariableBounds {
+            lower: Some(value::zero()),
+            upper: Some(value::one())
+        },
+        mip.program.bounds[2]
+    );
+    let result = mip.solve();
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(Some(frac(8, 1)), result.bound);
+}
+
+#[test]
+fn parse_mip_reads_continuous_models() {
+    let mip = sut::parse_mip("min x\nst\n x >= 2\n").unwrap();
+    assert!(mip.integer_variables.is_empty());
+    assert_eq!(
+        sut::parse::<Value>("min x\nst\n x >= 2\n").unwrap(),
+        mip.program
+    );
+}
+
+#[test]
+fn reports_non_variable_in_integer_section() {
+    let error = sut::parse_mip("max x\ngeneral\n x 3\n").unwrap_err();
+    assert_eq!((3, 4), (error.line, error.column));
+    assert_eq!("expected a variable name", error.message);
+}
+
+#[test]
+fn reports_semi_continuous_sections_as_unsupported() {
+    let error = sut::parse_mip("max x\nsemi-continuous\n x\n").unwrap_err();
+    assert_eq!((2, 1), (error.line, error.column));
+    assert_eq!("unsupported section", error.message);
+}
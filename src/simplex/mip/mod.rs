@@ -0,0 +1,329 @@
+#[cfg(test)]
+mod test;
+
+use fraction::CheckedMul;
+
+use crate::simplex::linearprogram::{LinearProgram, Solution, Term, VariableBounds};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::{gomory, primal, EmptyObserver, SolveStatus};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, ObjectiveSense, Operator, Variable};
+
+/// A linear program some of whose variables must take integer values.
+/// Values are exact fractions, so a relaxation's fractional variables are
+/// told apart from integers without a tolerance.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MixedIntegerProgram {
+    pub program: LinearProgram<Value>,
+    pub integer_variables: Vec<Variable>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct MipOptions {
+    pub node_limit: usize,
+    /// Rounds of Gomory cuts to add to the root relaxation before
+    /// branching. Cuts are only added to pure integer programs, see
+    /// `with_cuts`.
+    pub cut_rounds: usize,
+}
+
+impl Default for MipOptions {
+    fn default() -> Self {
+        Self {
+            node_limit: 10_000,
+            cut_rounds: 0,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct MipSolution {
+    /// `Optimal` when the search finished with an integer solution and
+    /// `IterationLimit` when it was cut short.
+    pub status: SolveStatus,
+    /// The best integer solution found.
+    pub solution: Option<Solution<Value, MObjectiveValue>>,
+    /// The best objective value an integer solution could still reach, if
+    /// known.
+    pub bound: Option<Value>,
+    /// How far `solution` may be from optimal: the distance between its
+    /// objective value and `bound`.
+    pub gap: Option<Value>,
+    /// The number of relaxations solved.
+    pub nodes: usize,
+}
+
+/// A subproblem: the program under tightened variable bounds, with the
+/// objective value of the relaxation it was branched from.
+struct Node {
+    bounds: Vec<VariableBounds<Value>>,
+    parent_bound: Option<Value>,
+}
+
+impl MixedIntegerProgram {
+    pub fn new(program: LinearProgram<Value>) -> Self {
+        Self {
+            program,
+            integer_variables: vec![],
+        }
+    }
+
+    pub fn set_integer(&mut self, var: Variable) {
+        if !self.integer_variables.contains(&var) {
+            self.integer_variables.push(var);
+        }
+    }
+
+    /// Makes `var` an integer between zero and one.
+    pub fn set_binary(&mut self, var: Variable) {
+        self.set_integer(var);
+        self.program.set_lower_bound(var, Some(value::zero()));
+        self.program.set_upper_bound(var, Some(value::one()));
+    }
+
+    pub fn solve(&self) -> MipSolution {
+        self.solve_with_options(&MipOptions::default())
+    }
+
+    /// Depth-first branch and bound. Each node solves the LP relaxation
+    /// under its variable bounds. A relaxation no better than the incumbent
+    /// is pruned, and one where an integer variable takes a fractional
+    /// value `v` branches into `x <= floor(v)` and `x >= ceil(v)`.
+    pub fn solve_with_options(&self, options: &MipOptions) -> MipSolution {
+        let program = self.with_cuts(options.cut_rounds);
+        let mut incumbent: Option<Solution<Value, MObjectiveValue>> = None;
+        let mut open = vec![Node {
+            bounds: program.bounds.clone(),
+            parent_bound: None,
+        }];
+        let mut nodes = 0;
+        while let Some(node) = open.pop() {
+            if let (Some(best), Some(parent_bound)) = (&incumbent, &node.parent_bound)
+                && !self.improves(parent_bound, &objective(best))
+            {
+                continue;
+            }
+            if nodes == options.node_limit {
+                open.push(node);
+                return self.stopped(incumbent, &open, nodes);
+            }
+            nodes += 1;
+            let mut relaxation = program.clone();
+            relaxation.bounds = node.bounds;
+            let solution = relaxation.solve::<MObjectiveValue>(&mut EmptyObserver {});
+            match solution.status {
+                SolveStatus::Optimal => {}
+                SolveStatus::Infeasible => continue,
+                SolveStatus::Unbounded => {
+                    return MipSolution {
+                        status: SolveStatus::Unbounded,
+                        solution: None,
+                        bound: None,
+                        gap: None,
+                        nodes,
+                    };
+                }
+                SolveStatus::IterationLimit => {
+                    open.push(Node {
+                        bounds: relaxation.bounds,
+                        parent_bound: node.parent_bound,
+                    });
+                    return self.stopped(incumbent, &open, nodes);
+                }
+            }
+            let relaxation_bound = objective(&solution);
+            if let Some(best) = &incumbent
+                && !self.improves(&relaxation_bound, &objective(best))
+            {
+                continue;
+            }
+            let Some((var, fractional)) = self.fractional_variable(&solution) else {
+                incumbent = Some(solution);
+                continue;
+            };
+            let mut down = relaxation.bounds.clone();
+            down[var].upper = Some(fractional.floor());
+            let mut up = relaxation.bounds;
+            up[var].lower = Some(fractional.ceil());
+            open.push(Node {
+                bounds: up,
+                parent_bound: Some(relaxation_bound),
+            });
+            open.push(Node {
+                bounds: down,
+                parent_bound: Some(relaxation_bound),
+            });
+        }
+        match incumbent {
+            Some(best) => MipSolution {
+                status: SolveStatus::Optimal,
+                bound: Some(objective(&best)),
+                gap: Some(value::zero()),
+                solution: Some(best),
+                nodes,
+            },
+            None => MipSolution {
+                status: SolveStatus::Infeasible,
+                solution: None,
+                bound: None,
+                gap: None,
+                nodes,
+            },
+        }
+    }
+
+    /// The program with up to `rounds` rounds of Gomory cuts from its
+    /// relaxation added as constraints named `"cut{round}.{k}"`. Each round
+    /// solves the relaxation, reads the cuts off its final tableau and
+    /// rewrites them in the variables, scaled to integer coefficients so the
+    /// next round's rows stay integral. Cuts stop at the first one whose
+    /// scaling overflows. The program is returned unchanged
+    /// unless every variable is integer with an integer lower bound and the
+    /// constraints have integer coefficients and bounds, since the cuts are
+    /// not valid otherwise.
+    pub fn with_cuts(&self, rounds: usize) -> LinearProgram<Value> {
+        let mut program = self.program.clone();
+        if !self.is_pure_integer() {
+            return program;
+        }
+        for round in 0..rounds {
+            let initial = program.problem::<MObjectiveValue>();
+            let result = primal::solve(initial.clone(), &mut EmptyObserver {});
+            if result.status != SolveStatus::Optimal {
+                break;
+            }
+            let cuts: Vec<Constraint<Value>> = (0..result.tableau.rows.len())
+                .filter_map(|row_idx| gomory::cut(&result.tableau, row_idx))
+                .collect();
+            if cuts.is_empty() {
+                break;
+            }
+            for (k, cut) in cuts.iter().enumerate() {
+                let cut = gomory::in_decision_variables(&initial, cut);
+                let shifted_bound = program.bounds.iter().zip(&cut.coefficients).fold(
+                    cut.bound,
+                    |bound, (bounds, coeff)| {
+                        bound + *coeff * bounds.lower.unwrap_or_else(value::zero)
+                    },
+                );
+                let Some(scale) =
+                    common_denominator(cut.coefficients.iter().chain([&shifted_bound]))
+                else {
+                    return program;
+                };
+                let scale = Value::from(scale);
+                let terms: Option<Vec<Term<Value>>> = cut
+                    .coefficients
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, coeff)| **coeff != value::zero())
+                    .map(|(var, coeff)| Some((var, coeff.checked_mul(&scale)?)))
+                    .collect();
+                let (Some(terms), Some(bound)) = (terms, shifted_bound.checked_mul(&scale)) else {
+                    return program;
+                };
+                program.add_constraint(
+                    &format!("cut{round}.{k}"),
+                    terms,
+                    Operator::GREATERTHANEQUAL,
+                    bound,
+                );
+            }
+        }
+        program
+    }
+
+    /// Whether every column of the lowered program is an integer in every
+    /// integer solution: each variable is integer with an integer lower
+    /// bound and upper bound, and the constraints are integral.
+    fn is_pure_integer(&self) -> bool {
+        let is_integer = |value: &Value| value.fract() == value::zero();
+        (0..self.program.variables.len()).all(|var| self.integer_variables.contains(&var))
+            && self.program.bounds.iter().all(|bounds| {
+                bounds.lower.as_ref().is_some_and(is_integer)
+                    && bounds.upper.as_ref().is_none_or(is_integer)
+            })
+            && self.program.constraints.iter().all(|constraint| {
+                is_integer(&constraint.bound)
+                    && constraint.range.as_ref().is_none_or(is_integer)
+                    && constraint.terms.iter().all(|(_, coeff)| is_integer(coeff))
+            })
+    }
+
+    /// The first integer variable with a fractional value, and that value.
+    fn fractional_variable(
+        &self,
+        solution: &Solution<Value, MObjectiveValue>,
+    ) -> Option<(Variable, Value)> {
+        self.integer_variables
+            .iter()
+            .map(|&var| (var, solution.values[var].1))
+            .find(|(_, var_value)| var_value.fract() != value::zero())
+    }
+
+    fn improves(&self, candidate: &Value, incumbent: &Value) -> bool {
+        match self.program.sense {
+            ObjectiveSense::Maximize => candidate > incumbent,
+            ObjectiveSense::Minimize => candidate < incumbent,
+        }
+    }
+
+    /// The result of a search cut short with `open` nodes left. The bound
+    /// is the best of their parents' relaxations and the incumbent, and is
+    /// unknown while the root relaxation is unsolved.
+    fn stopped(
+        &self,
+        incumbent: Option<Solution<Value, MObjectiveValue>>,
+        open: &[Node],
+        nodes: usize,
+    ) -> MipSolution {
+        let parent_bounds: Option<Vec<Value>> = open.iter().map(|node| node.parent_bound).collect();
+        let bound = parent_bounds.map(|parent_bounds| {
+            parent_bounds
+                .into_iter()
+                .chain(incumbent.as_ref().map(objective))
+                .reduce(|best, bound| {
+                    if self.improves(&bound, &best) {
+                        bound
+                    } else {
+                        best
+                    }
+                })
+                .expect("a stopped search has an open node")
+        });
+        let gap = match (&incumbent, bound) {
+            (Some(best), Some(bound)) => Some((bound - objective(best)).abs()),
+            _ => None,
+        };
+        MipSolution {
+            status: SolveStatus::IterationLimit,
+            solution: incumbent,
+            bound,
+            gap,
+            nodes,
+        }
+    }
+}
+
+/// The least common multiple of the denominators of `values`, or `None`
+/// if it overflows.
+fn common_denominator<'a>(values: impl Iterator<Item = &'a Value>) -> Option<u64> {
+    values
+        .filter_map(|value| value.denom().copied())
+        .try_fold(1u64, |lcm, denom| {
+            (lcm / gcd(lcm, denom)).checked_mul(denom)
+        })
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn objective(solution: &Solution<Value, MObjectiveValue>) -> Value {
+    solution.objective_value.finite_part()
+}
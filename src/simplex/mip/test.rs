@@ -0,0 +1,256 @@
+use crate::simplex::linearprogram::LinearProgram;
+use crate::simplex::mip::{self as sut};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::tabular::SolveStatus;
+use crate::simplex::test::frac;
+use crate::simplex::value::{self, Value};
+use crate::simplex::{ObjectiveSense, Operator};
+
+fn make_knapsack() -> sut::MixedIntegerProgram {
+    let mut program = LinearProgram::new(ObjectiveSense::Maximize);
+    let items: Vec<_> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|name| program.add_variable(name))
+        .collect();
+    let values = [8, 11, 6, 4];
+    let weights = [5, 7, 4, 3];
+    program.set_objective(
+        items
+            .iter()
+            .zip(values)
+            .map(|(item, value)| (*item, frac(value, 1)))
+            .collect(),
+    );
+    program.add_constraint(
+        "capacity",
+        items
+            .iter()
+            .zip(weights)
+            .map(|(item, weight)| (*item, frac(weight, 1)))
+            .collect(),
+        Operator::LESSTHANEQUAL,
+        frac(14, 1),
+    );
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    for item in items {
+        mip.set_binary(item);
+    }
+    mip
+}
+
+// maximize x + y subject to 3x + 2y <= 12 and 2x + 3y <= 12 over the
+// integers, whose relaxation is optimal at x = y = 12/5.
+fn make_integer_program() -> sut::MixedIntegerProgram {
+    let mut program = LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    let y = program.add_variable("y");
+    program.set_objective(vec![(x, frac(1, 1)), (y, frac(1, 1))]);
+    program.add_constraint(
+        "c1",
+        vec![(x, frac(3, 1)), (y, frac(2, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(12, 1),
+    );
+    program.add_constraint(
+        "c2",
+        vec![(x, frac(2, 1)), (y, frac(3, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(12, 1),
+    );
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    mip.set_integer(x);
+    mip.set_integer(y);
+    mip
+}
+
+fn objective(result: &sut::MipSolution) -> Value {
+    result
+        .solution
+        .as_ref()
+        .expect("an integer solution")
+        .objective_value
+        .finite_part()
+}
+
+#[test]
+fn solves_binary_knapsack() {
+    let result = make_knapsack().solve();
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(frac(21, 1), objective(&result));
+    let solution = result.solution.unwrap();
+    assert_eq!(Some(&frac(0, 1)), solution.value("a"));
+    assert_eq!(Some(&frac(1, 1)), solution.value("b"));
+    assert_eq!(Some(&frac(1, 1)), solution.value("c"));
+    assert_eq!(Some(&frac(1, 1)), solution.value("d"));
+    assert_eq!(Some(frac(21, 1)), result.bound);
+    assert_eq!(Some(value::zero()), result.gap);
+}
+
+#[test]
+fn set_binary_bounds_variable_between_zero_and_one() {
+    let mip = make_knapsack();
+    assert_eq!(vec![0, 1, 2, 3], mip.integer_variables);
+    assert!(mip
+        .program
+        .bounds
+        .iter()
+        .all(|bounds| bounds.lower == Some(value::zero()) && bounds.upper == Some(value::one())));
+}
+
+#[test]
+fn set_integer_marks_variable_once() {
+    let mut program = LinearProgram::<Value>::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    mip.set_integer(x);
+    mip.set_integer(x);
+    assert_eq!(vec![x], mip.integer_variables);
+}
+
+#[test]
+fn branches_on_general_integer_variables() {
+    let result = make_integer_program().solve();
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(frac(4, 1), objective(&result));
+    assert!(result.nodes > 1);
+    let solution = result.solution.unwrap();
+    assert!(solution
+        .values
+        .iter()
+        .all(|(_, value)| value.fract() == value::zero()));
+}
+
+#[test]
+fn adds_gomory_cuts_in_variables() {
+    let program = make_integer_program().with_cuts(1);
+    assert_eq!(4, program.constraints.len());
+    let cut = &program.constraints[2];
+    assert_eq!("cut0.0", cut.name);
+    assert_eq!(vec![(0, -frac(3, 1)), (1, -frac(3, 1))], cut.terms);
+    assert_eq!(Operator::GREATERTHANEQUAL, cut.operator);
+    assert_eq!(-frac(14, 1), cut.bound);
+}
+
+#[test]
+fn solves_with_root_cuts() {
+    let options = sut::MipOptions {
+        cut_rounds: 5,
+        ..Default::default()
+    };
+    let result = make_integer_program().solve_with_options(&options);
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(frac(4, 1), objective(&result));
+    assert!(result.nodes <= make_integer_program().solve().nodes);
+}
+
+#[test]
+fn adds_no_cuts_with_continuous_variable() {
+    let mut mip = make_integer_program();
+    mip.integer_variables.pop();
+    assert_eq!(mip.program, mip.with_cuts(1));
+}
+
+#[test]
+fn adds_no_cuts_with_fractional_coefficients() {
+    let mut mip = make_integer_program();
+    mip.program.constraints[0].bound = frac(23, 2);
+    assert_eq!(mip.program, mip.with_cuts(1));
+}
+
+#[test]
+fn minimizes_with_negative_fractional_values() {
+    let mut program = LinearProgram::new(ObjectiveSense::Minimize);
+    let x = program.add_variable("x");
+    program.set_free(x);
+    program.set_objective(vec![(x, frac(1, 1))]);
+    program.add_constraint(
+        "c1",
+        vec![(x, frac(2, 1))],
+        Operator::GREATERTHANEQUAL,
+        -frac(3, 1),
+    );
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    mip.set_integer(x);
+    let result = mip.solve();
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(-frac(1, 1), objective(&result));
+    assert_eq!(Some(&-frac(1, 1)), result.solution.unwrap().value("x"));
+}
+
+#[test]
+fn reports_infeasible_when_no_integer_point_exists() {
+    let mut program = LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_objective(vec![(x, frac(1, 1))]);
+    program.add_constraint("c1", vec![(x, frac(2, 1))], Operator::EQUAL, frac(1, 1));
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    mip.set_integer(x);
+    let result = mip.solve();
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(None, result.solution);
+    assert_eq!(3, result.nodes);
+}
+
+#[test]
+fn reports_unbounded_relaxation() {
+    let mut program = LinearProgram::new(ObjectiveSense::Maximize);
+    let x = program.add_variable("x");
+    program.set_objective(vec![(x, frac(1, 1))]);
+    let mut mip = sut::MixedIntegerProgram::new(program);
+    mip.set_integer(x);
+    assert_eq!(SolveStatus::Unbounded, mip.solve().status);
+}
+
+#[test]
+fn stops_at_node_limit_with_relaxation_bound() {
+    let result = make_knapsack().solve_with_options(&sut::MipOptions {
+        node_limit: 1,
+        ..Default::default()
+    });
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(1, result.nodes);
+    assert_eq!(None, result.solution);
+    assert_eq!(Some(frac(22, 1)), result.bound);
+    assert_eq!(None, result.gap);
+}
+
+#[test]
+fn reports_gap_between_incumbent_and_open_nodes() {
+    let full = make_knapsack().solve();
+    let result = (1..full.nodes)
+        .map(|node_limit| {
+            make_knapsack().solve_with_options(&sut::MipOptions {
+                node_limit,
+                ..Default::default()
+            })
+        })
+        .find(|result| result.solution.is_some())
+        .expect("an incumbent before the search ends");
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    let gap = result.gap.unwrap();
+    assert_eq!(result.bound.unwrap() + -objective(&result), gap);
+    assert!(gap >= value::zero());
+}
+
+#[test]
+fn stops_before_root_with_unknown_bound() {
+    let result = make_knapsack().solve_with_options(&sut::MipOptions {
+        node_limit: 0,
+        ..Default::default()
+    });
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(0, result.nodes);
+    assert_eq!(None, result.bound);
+}
+
+#[test]
+fn common_denominator_is_least_common_multiple() {
+    let values = [frac(1, 4), frac(5, 6), frac(3, 1)];
+    assert_eq!(Some(12), sut::common_denominator(values.iter()));
+}
+
+#[test]
+fn common_denominator_reports_overflow() {
+    let values = [frac(1, (1 << 32) + 1), frac(1, (1 << 32) + 3)];
+    assert_eq!(None, sut::common_denominator(values.iter()));
+}
@@ -1,8 +1,17 @@
 #[cfg(test)]
 mod test;
 
+pub mod bigvalue;
+pub mod duality;
+pub mod float;
+pub mod linearprogram;
+pub mod lp;
+pub mod mip;
+pub mod mps;
 pub mod objectivevalue;
+pub mod revised;
 pub mod rowvalue;
+pub mod sparse;
 pub mod tabular;
 pub mod value;
 
@@ -11,13 +20,20 @@ use crate::simplex::rowvalue::RowValue;
 pub type Coefficients<R> = Vec<R>;
 pub type Variable = usize;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum Operator {
     LESSTHANEQUAL,
+    GREATERTHANEQUAL,
     EQUAL,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ObjectiveSense {
+    Maximize,
+    Minimize,
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub struct Constraint<R: RowValue> {
     pub operator: Operator,
     pub coefficients: Coefficients<R>,
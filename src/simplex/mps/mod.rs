@@ -0,0 +1,408 @@
+#[cfg(test)]
+mod test;
+
+use std::collections::HashMap;
+
+use crate::simplex::linearprogram::{LinearProgram, Solution, VariableBounds};
+use crate::simplex::lp::ParseError;
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::{self, RowValue};
+use crate::simplex::value;
+use crate::simplex::{ObjectiveSense, Operator};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Section {
+    Name,
+    ObjSense,
+    Rows,
+    Columns,
+    Rhs,
+    Ranges,
+    Bounds,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum RowRef {
+    Objective,
+    Free,
+    Constraint(usize),
+}
+
+type Field<'a> = (usize, &'a str);
+
+struct Parser<R: RowValue> {
+    program: LinearProgram<R>,
+    rows: HashMap<String, RowRef>,
+    line: usize,
+}
+
+/// Parses a model in free MPS format. Supports the NAME, OBJSENSE, ROWS,
+/// COLUMNS, RHS, RANGES and BOUNDS sections; the first N row is the
+/// objective, which is minimized unless OBJSENSE says otherwise. Numbers
+/// are read as `R`, as in `lp::parse`.
+pub fn parse<R: RowValue>(input: &str) -> Result<LinearProgram<R>, ParseError> {
+    let mut parser = Parser {
+        program: LinearProgram::new(ObjectiveSense::Minimize),
+        rows: HashMap::new(),
+        line: 0,
+    };
+    let mut section = None;
+    for (line_idx, line) in input.lines().enumerate() {
+        parser.line = line_idx + 1;
+        let fields = fields(line);
+        if fields.is_empty() || line.starts_with('*') {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            let (column, header) = fields[0];
+            section = Some(match header.to_ascii_uppercase().as_str() {
+                "NAME" => Section::Name,
+                "OBJSENSE" => Section::ObjSense,
+                "ROWS" => Section::Rows,
+                "COLUMNS" => Section::Columns,
+                "RHS" => Section::Rhs,
+                "RANGES" => Section::Ranges,
+                "BOUNDS" => Section::Bounds,
+                "ENDATA" => break,
+                _ => return Err(parser.error(column, "unknown section")),
+            });
+            if section == Some(Section::ObjSense) && fields.len() > 1 {
+                parser.objective_sense(&fields[1..])?;
+            }
+            continue;
+        }
+        match section {
+            Some(Section::Name) => {}
+            Some(Section::ObjSense) => parser.objective_sense(&fields)?,
+            Some(Section::Rows) => parser.row(&fields)?,
+            Some(Section::Columns) => parser.column(&fields)?,
+            Some(Section::Rhs) => parser.rhs(&fields)?,
+            Some(Section::Ranges) => parser.range(&fields)?,
+            Some(Section::Bounds) => parser.bound(&fields)?,
+            None => return Err(parser.error(fields[0].0, "expected a section header")),
+        }
+    }
+    Ok(parser.program)
+}
+
+/// Writes a model in free MPS format.
+pub fn write<R: RowValue>(name: &str, program: &LinearProgram<R>) -> String {
+    let objective_row = objective_row_name(program);
+    let mut out = format!("NAME {}\n", name);
+    if program.sense == ObjectiveSense::Maximize {
+        out += "OBJSENSE\n    MAX\n";
+    }
+    out += &format!("ROWS\n N  {}\n", objective_row);
+    for constraint in &program.constraints {
+        let kind = match constraint.operator {
+            Operator::LESSTHANEQUAL => "L",
+            Operator::GREATERTHANEQUAL => "G",
+            Operator::EQUAL => "E",
+        };
+        out += &format!(" {}  {}\n", kind, constraint.name);
+    }
+    out += "COLUMNS\n";
+    let objective_coeffs = program.objective_coeffs();
+    let constraints = program.functional_constraints();
+    for (var, var_name) in program.variables.iter().enumerate() {
+        let entries: Vec<(&str, &R)> =
+            std::iter::once((objective_row.as_str(), &objective_coeffs[var]))
+                .chain(
+                    program
+                        .constraints
+                        .iter()
+                        .zip(&constraints)
+                        .map(|(named, constraint)| {
+                            (named.name.as_str(), &constraint.coefficients[var])
+                        }),
+                )
+                .filter(|(_, coeff)| **coeff != R::zero())
+                .collect();
+        if entries.is_empty() {
+            out += &format!("    {}  {}  0\n", var_name, objective_row);
+        }
+        for (row, coeff) in entries {
+            out += &format!(
+                "    {}  {}  {}\n",
+                var_name,
+                row,
+                value::format_decimal(coeff)
+            );
+        }
+    }
+    out += "RHS\n";
+    for constraint in &program.constraints {
+        if constraint.bound != R::zero() {
+            out += &format!(
+                "    RHS  {}  {}\n",
+                constraint.name,
+                value::format_decimal(&constraint.bound)
+            );
+        }
+    }
+    let ranges: Vec<String> = program
+        .constraints
+        .iter()
+        .filter_map(|constraint| {
+            let range = constraint.range.as_ref()?;
+            Some(format!(
+                "    RNG  {}  {}\n",
+                constraint.name,
+                value::format_decimal(range)
+            ))
+        })
+        .collect();
+    if !ranges.is_empty() {
+        out += "RANGES\n";
+        out += &ranges.concat();
+    }
+    let bounds: Vec<String> = program
+        .variables
+        .iter()
+        .zip(&program.bounds)
+        .flat_map(|(var_name, bounds)| bound_lines(var_name, bounds))
+        .collect();
+    if !bounds.is_empty() {
+        out += "BOUNDS\n";
+        out += &bounds.concat();
+    }
+    out += "ENDATA\n";
+    out
+}
+
+/// The BOUNDS lines for a variable, none if it keeps the default `>= 0`.
+fn bound_lines<R: RowValue>(var_name: &str, bounds: &VariableBounds<R>) -> Vec<String> {
+    let line = |kind: &str, bound: Option<&R>| match bound {
+        Some(bound) => format!(
+            " {} BND  {}  {}\n",
+            kind,
+            var_name,
+            value::format_decimal(bound)
+        ),
+        None => format!(" {} BND  {}\n", kind, var_name),
+    };
+    match (&bounds.lower, &bounds.upper) {
+        (None, None) => vec![line("FR", None)],
+        (Some(lower), Some(upper)) if lower == upper => vec![line("FX", Some(lower))],
+        (lower, upper) => {
+            let upper_is_negative = upper.as_ref().is_some_and(|upper| *upper < R::zero());
+            let lower_line = match lower {
+                None => Some(line("MI", None)),
+                Some(lower) if *lower != R::zero() || upper_is_negative => {
+                    Some(line("LO", Some(lower)))
+                }
+                Some(_) => None,
+            };
+            let upper_line = upper.as_ref().map(|upper| line("UP", Some(upper)));
+            // A zero lower bound follows a negative upper bound, which
+            // would otherwise clear it.
+            if upper_is_negative {
+                upper_line.into_iter().chain(lower_line).collect()
+            } else {
+                lower_line.into_iter().chain(upper_line).collect()
+            }
+        }
+    }
+}
+
+/// Writes a model in free MPS format, preceded by comment lines recording
+/// the status, objective value and variable values of `solution`.
+pub fn write_solution<R: RowValue, O: ObjectiveValue<R>>(
+    name: &str,
+    program: &LinearProgram<R>,
+    solution: &Solution<R, O>,
+) -> String {
+    let mut out = format!(
+        "* status: {:?}\n* objective: {}\n",
+        solution.status, solution.objective_value
+    );
+    for (var_name, var_value) in &solution.values {
+        out += &format!("* {} = {}\n", var_name, value::format_decimal(var_value));
+    }
+    out + &write(name, program)
+}
+
+fn objective_row_name<R: RowValue>(program: &LinearProgram<R>) -> String {
+    let mut name = "obj".to_string();
+    while program.constraints.iter().any(|c| c.name == name) {
+        name.push('_');
+    }
+    name
+}
+
+fn fields(line: &str) -> Vec<Field<'_>> {
+    let mut fields = vec![];
+    let mut start = None;
+    for (idx, c) in line.char_indices() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(idx),
+            (true, Some(s)) => {
+                fields.push((s + 1, &line[s..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        fields.push((s + 1, &line[s..]));
+    }
+    fields
+}
+
+impl<R: RowValue> Parser<R> {
+    fn error(&self, column: usize, message: &str) -> ParseError {
+        ParseError {
+            line: self.line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    fn number(&self, (column, text): Field) -> Result<R, ParseError> {
+        rowvalue::parse_decimal(text).ok_or_else(|| self.error(column, "expected a number"))
+    }
+
+    fn row_ref(&self, (column, name): Field) -> Result<RowRef, ParseError> {
+        self.rows
+            .get(name)
+            .copied()
+            .ok_or_else(|| self.error(column, "unknown row"))
+    }
+
+    fn objective_sense(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        let (column, sense) = fields[0];
+        self.program.sense = match sense.to_ascii_uppercase().as_str() {
+            "MAX" | "MAXIMIZE" => ObjectiveSense::Maximize,
+            "MIN" | "MINIMIZE" => ObjectiveSense::Minimize,
+            _ => return Err(self.error(column, "expected MAX or MIN")),
+        };
+        Ok(())
+    }
+
+    fn row(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        let [(column, kind), (_, name)] = fields else {
+            return Err(self.error(fields[0].0, "expected a row type and name"));
+        };
+        let operator = match kind.to_ascii_uppercase().as_str() {
+            "N" => None,
+            "L" => Some(Operator::LESSTHANEQUAL),
+            "G" => Some(Operator::GREATERTHANEQUAL),
+            "E" => Some(Operator::EQUAL),
+            _ => return Err(self.error(*column, "unknown row type")),
+        };
+        let row = match operator {
+            None if self.rows.values().any(|row| *row == RowRef::Objective) => RowRef::Free,
+            None => RowRef::Objective,
+            Some(operator) => {
+                RowRef::Constraint(
+                    self.program
+                        .add_constraint(name, vec![], operator, R::zero()),
+                )
+            }
+        };
+        self.rows.insert(name.to_string(), row);
+        Ok(())
+    }
+
+    fn column(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        if fields.iter().any(|(_, field)| field.contains("MARKER")) {
+            return Err(self.error(fields[0].0, "integer variables are not supported"));
+        }
+        if fields.len() != 3 && fields.len() != 5 {
+            return Err(self.error(fields[0].0, "expected a column, row and value"));
+        }
+        let var = self.program.add_variable(fields[0].1);
+        for pair in fields[1..].chunks(2) {
+            let coeff = self.number(pair[1])?;
+            match self.row_ref(pair[0])? {
+                RowRef::Objective => self.program.objective.push((var, coeff)),
+                RowRef::Free => {}
+                RowRef::Constraint(idx) => self.program.constraints[idx].terms.push((var, coeff)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits off the optional set name of an RHS or RANGES line, leaving
+    /// row and value pairs.
+    fn row_values<'a, 'b>(&self, fields: &'b [Field<'a>]) -> Result<&'b [Field<'a>], ParseError> {
+        let pairs = if fields.len() % 2 == 1 {
+            &fields[1..]
+        } else {
+            fields
+        };
+        if pairs.is_empty() || pairs.len() > 4 {
+            return Err(self.error(fields[0].0, "expected a row and value"));
+        }
+        Ok(pairs)
+    }
+
+    fn rhs(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        for pair in self.row_values(fields)?.chunks(2) {
+            let bound = self.number(pair[1])?;
+            match self.row_ref(pair[0])? {
+                RowRef::Objective => {
+                    return Err(self.error(pair[0].0, "objective constants are not supported"));
+                }
+                RowRef::Free => {}
+                RowRef::Constraint(idx) => self.program.constraints[idx].bound = bound,
+            }
+        }
+        Ok(())
+    }
+
+    fn range(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        for pair in self.row_values(fields)?.chunks(2) {
+            let range = self.number(pair[1])?;
+            match self.row_ref(pair[0])? {
+                RowRef::Constraint(idx) => self.program.constraints[idx].range = Some(range),
+                _ => return Err(self.error(pair[0].0, "ranges only apply to L, G and E rows")),
+            }
+        }
+        Ok(())
+    }
+
+    fn bound(&mut self, fields: &[Field]) -> Result<(), ParseError> {
+        let (column, kind) = fields[0];
+        let kind = kind.to_ascii_uppercase();
+        let has_value = matches!(kind.as_str(), "UP" | "LO" | "FX");
+        let field_count = if has_value { 3 } else { 2 };
+        let rest = match fields.len().checked_sub(field_count) {
+            Some(0) => &fields[1..],
+            Some(1) => &fields[2..],
+            _ => return Err(self.error(column, "expected a bound type, column and value")),
+        };
+        let var_field = rest[0];
+        let Some(var) = self.program.variable(var_field.1) else {
+            return Err(self.error(var_field.0, "unknown column"));
+        };
+        let bound = if has_value {
+            self.number(rest[1])?
+        } else {
+            R::zero()
+        };
+        match kind.as_str() {
+            "UP" => {
+                // A negative upper bound on a variable still at the default
+                // lower bound of zero makes it unbounded below.
+                if bound < R::zero() && self.program.bounds[var].lower == Some(R::zero()) {
+                    self.program.set_lower_bound(var, None);
+                }
+                self.program.set_upper_bound(var, Some(bound));
+            }
+            "LO" => self.program.set_lower_bound(var, Some(bound)),
+            "FX" => {
+                self.program.set_lower_bound(var, Some(bound.clone()));
+                self.program.set_upper_bound(var, Some(bound));
+            }
+            "FR" => self.program.set_free(var),
+            "MI" => self.program.set_lower_bound(var, None),
+            "PL" => self.program.set_upper_bound(var, None),
+            "BV" | "LI" | "UI" | "SC" => {
+                return Err(self.error(column, "integer bounds are not supported"));
+            }
+            _ => return Err(self.error(column, "unknown bound type")),
+        }
+        Ok(())
+    }
+}
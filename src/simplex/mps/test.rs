@@ -0,0 +1,410 @@
+use fraction::BigUint;
+
+use crate::simplex::bigvalue::BigValue;
+use crate::simplex::linearprogram::{LinearProgram, VariableBounds};
+use crate::simplex::mps::{self as sut};
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::{EmptyObserver, SolveStatus};
+use crate::simplex::test::frac;
+use crate::simplex::value::Value;
+use crate::simplex::{lp, ObjectiveSense, Operator};
+
+const PRODUCTION: &str = "NAME production
+* a small production model
+OBJSENSE
+    MAX
+ROWS
+ N  profit
+ L  wood
+ G  labour
+ E  balance
+COLUMNS
+    x  profit  3  wood  1
+    x  labour  1  balance  -1
+    y  profit  2
+    y  wood  1  labour  3
+    y  balance  1
+RHS
+    RHS  wood  4  labour  2
+    RHS  balance  1
+ENDATA
+";
+
+fn parse_error(input: &str) -> lp::ParseError {
+    sut::parse::<Value>(input).unwrap_err()
+}
+
+#[test]
+fn parses_rows_columns_and_rhs() {
+    let program = sut::parse::<Value>(PRODUCTION).unwrap();
+    assert_eq!(ObjectiveSense::Maximize, program.sense);
+    assert_eq!(vec!["x".to_string(), "y".to_string()], program.variables);
+    assert_eq!(vec![frac(3, 1), frac(2, 1)], program.objective_coeffs());
+    let constraints = program.functional_constraints();
+    assert_eq!(3, constraints.len());
+    assert_eq!(Operator::LESSTHANEQUAL, constraints[0].operator);
+    assert_eq!(frac(4, 1), constraints[0].bound);
+    assert_eq!(Operator::GREATERTHANEQUAL, constraints[1].operator);
+    assert_eq!(vec![frac(1, 1), frac(3, 1)], constraints[1].coefficients);
+    assert_eq!(Operator::EQUAL, constraints[2].operator);
+    assert_eq!(vec![-frac(1, 1), frac(1, 1)], constraints[2].coefficients);
+    assert_eq!(frac(1, 1), constraints[2].bound);
+}
+
+#[test]
+fn defaults_to_minimize_and_drops_extra_free_rows() {
+    let program = sut::parse::<Value>(
+        "NAME diet
+ROWS
+ N  cost
+ N  weight
+ G  protein
+COLUMNS
+    a  cost  2  weight  5
+    a  protein  1
+    b  cost  3  protein  2
+RHS
+    protein  4
+ENDATA
+",
+    )
+    .unwrap();
+    assert_eq!(ObjectiveSense::Minimize, program.sense);
+    assert_eq!(vec![frac(2, 1), frac(3, 1)], program.objective_coeffs());
+    assert_eq!(1, program.constraints.len());
+    assert_eq!(frac(4, 1), program.constraints[0].bound);
+}
+
+#[test]
+fn parses_objective_sense_on_header_line() {
+    let program =
+        sut::parse::<Value>("NAME m\nOBJSENSE MAXIMIZE\nROWS\n N  obj\nENDATA\n").unwrap();
+    assert_eq!(ObjectiveSense::Maximize, program.sense);
+}
+
+const RANGES: &str = "NAME ranges
+ROWS
+ N  obj
+ L  cap
+ G  demand
+ E  mix
+COLUMNS
+    x  obj  1  cap  1
+    x  demand  1  mix  1
+RHS
+    RHS  cap  10  demand  2
+    RHS  mix  5
+RANGES
+    RNG  cap  4  demand  -7
+    RNG  mix  3
+ENDATA
+";
+
+#[test]
+fn parses_ranges_onto_their_rows() {
+    let program = sut::parse::<Value>(RANGES).unwrap();
+    let rows: Vec<(&str, Operator, Value, Option<Value>)> = program
+        .constraints
+        .iter()
+        .map(|c| (c.name.as_str(), c.operator.clone(), c.bound, c.range))
+        .collect();
+    assert_eq!(
+        vec![
+            (
+                "cap",
+                Operator::LESSTHANEQUAL,
+                frac(10, 1),
+                Some(frac(4, 1))
+            ),
+            (
+                "demand",
+                Operator::GREATERTHANEQUAL,
+                frac(2, 1),
+                Some(-frac(7, 1))
+            ),
+            ("mix", Operator::EQUAL, frac(5, 1), Some(frac(3, 1))),
+        ],
+        rows
+    );
+}
+
+#[test]
+fn writes_ranges_that_parse_back() {
+    let program = sut::parse::<Value>(RANGES).unwrap();
+    let written = sut::write("ranges", &program);
+    assert!(written.contains(
+        "RANGES
+    RNG  cap  4
+    RNG  demand  -7
+    RNG  mix  3
+ENDATA
+"
+    ));
+    assert_eq!(program, sut::parse::<Value>(&written).unwrap());
+}
+
+#[test]
+fn solves_within_ranges() {
+    let mut program = sut::parse::<Value>(RANGES).unwrap();
+    // cap holds for 6 <= x <= 10, demand for 2 <= x <= 9 and mix for
+    // 5 <= x <= 8.
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    assert_eq!(Some(&frac(6, 1)), solution.value("x"));
+    program.sense = ObjectiveSense::Maximize;
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver {});
+    assert_eq!(Some(&frac(8, 1)), solution.value("x"));
+}
+
+#[test]
+fn parses_bounds_with_and_without_set_name() {
+    let program = sut::parse::<Value>(
+        "NAME bounds
+ROWS
+ N  obj
+COLUMNS
+    x  obj  1
+    y  obj  1
+    z  obj  1
+BOUNDS
+ UP BND  x  4
+ LO y  1.5
+ FX BND  z  2
+ PL BND  x
+ENDATA
+",
+    )
+    .unwrap();
+    assert!(program.constraints.is_empty());
+    assert_eq!(
+        vec![
+            VariableBounds::default(),
+            VariableBounds {
+                lower: Some(frac(3, 2)),
+                upper: None
+            },
+            VariableBounds {
+                lower: Some(frac(2, 1)),
+                upper: Some(frac(2, 1))
+            },
+        ],
+        program.bounds
+    );
+}
+
+#[test]
+fn parses_free_and_negative_bounds() {
+    let program = sut::parse::<Value>(
+        "NAME bounds
+ROWS
+ N  obj
+COLUMNS
+    x  obj  1
+    y  obj  1
+    z  obj  1
+BOUNDS
+ FR BND  x
+ MI BND  y
+ UP BND  y  -2
+ LO BND  z  -3
+ENDATA
+",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            VariableBounds {
+                lower: None,
+                upper: None
+            },
+            VariableBounds {
+                lower: None,
+                upper: Some(-frac(2, 1))
+            },
+            VariableBounds {
+                lower: Some(-frac(3, 1)),
+                upper: None
+            },
+        ],
+        program.bounds
+    );
+    assert_eq!(
+        program,
+        sut::parse::<Value>(&sut::write("bounds", &program)).unwrap()
+    );
+}
+
+#[test]
+fn negative_upper_bound_frees_default_lower_bound() {
+    let program = sut::parse::<Value>(
+        "NAME bounds
+ROWS
+ N  obj
+COLUMNS
+    x  obj  1
+    y  obj  1
+    z  obj  1
+BOUNDS
+ UP BND  x  -2
+ LO BND  y  -5
+ UP BND  y  -2
+ UP BND  z  -2
+ LO BND  z  0
+ENDATA
+",
+    )
+    .unwrap();
+    assert_eq!(
+        vec![
+            VariableBounds {
+                lower: None,
+                upper: Some(-frac(2, 1))
+            },
+            VariableBounds {
+                lower: Some(-frac(5, 1)),
+                upper: Some(-frac(2, 1))
+            },
+            VariableBounds {
+                lower: Some(frac(0, 1)),
+                upper: Some(-frac(2, 1))
+            },
+        ],
+        program.bounds
+    );
+    assert_eq!(
+        program,
+        sut::parse::<Value>(&sut::write("bounds", &program)).unwrap()
+    );
+}
+
+#[test]
+fn rejects_unsupported_bounds() {
+    let model = |bound: &str| {
+        format!(
+            "NAME m\nROWS\n N  obj\nCOLUMNS\n    x  obj  1\nBOUNDS\n {}\nENDATA\n",
+            bound
+        )
+    };
+    assert_eq!(
+        "integer bounds are not supported",
+        parse_error(&model("BV BND x")).message
+    );
+    assert_eq!("unknown column", parse_error(&model("UP BND w 1")).message);
+    assert_eq!(
+        "unknown bound type",
+        parse_error(&model("XX BND x")).message
+    );
+}
+
+#[test]
+fn parses_long_literals_exactly_as_big_values() {
+    let input = "NAME m
+ROWS
+ N  obj
+ L  c
+COLUMNS
+    x  c  1
+RHS
+    RHS  c  12345678901234567890123
+ENDATA
+";
+    let program = sut::parse::<BigValue>(input).unwrap();
+    assert_eq!(
+        BigValue::from(BigUint::from(12345678901234567890123u128)),
+        program.constraints[0].bound
+    );
+    let error = parse_error(input);
+    assert_eq!(
+        (8, 13, "expected a number"),
+        (error.line, error.column, error.message.as_str())
+    );
+}
+
+#[test]
+fn reports_location_of_errors() {
+    let error = parse_error("NAME m\nROWS\n N  obj\nCOLUMNS\n    x  cost  1\n");
+    assert_eq!((5, 8), (error.line, error.column));
+    assert_eq!("unknown row", error.message);
+    let error = parse_error("NAME m\nROWS\n N  obj\n L  c\nCOLUMNS\n    x  c  abc\n");
+    assert_eq!(
+        (6, 11, "expected a number"),
+        (error.line, error.column, error.message.as_str())
+    );
+}
+
+#[test]
+fn rejects_integer_markers_and_objective_constants() {
+    let error = parse_error("NAME m\nROWS\n N  obj\nCOLUMNS\n    M1  'MARKER'  'INTORG'\n");
+    assert_eq!("integer variables are not supported", error.message);
+    let error =
+        parse_error("NAME m\nROWS\n N  obj\nCOLUMNS\n    x  obj  1\nRHS\n    RHS  obj  5\n");
+    assert_eq!("objective constants are not supported", error.message);
+}
+
+#[test]
+fn writes_model_that_parses_back() {
+    let program = sut::parse::<Value>(PRODUCTION).unwrap();
+    let written = sut::write("production", &program);
+    assert_eq!(
+        "NAME production
+OBJSENSE
+    MAX
+ROWS
+ N  obj
+ L  wood
+ G  labour
+ E  balance
+COLUMNS
+    x  obj  3
+    x  wood  1
+    x  labour  1
+    x  balance  -1
+    y  obj  2
+    y  wood  1
+    y  labour  3
+    y  balance  1
+RHS
+    RHS  wood  4
+    RHS  labour  2
+    RHS  balance  1
+ENDATA
+",
+        written
+    );
+    assert_eq!(program, sut::parse::<Value>(&written).unwrap());
+}
+
+#[test]
+fn writes_exact_decimals() {
+    let mut program = LinearProgram::new(ObjectiveSense::Minimize);
+    let x = program.add_variable("x");
+    program.add_variable("unused");
+    program.set_objective(vec![(x, frac(5, 2))]);
+    program.add_constraint(
+        "c",
+        vec![(x, -frac(7, 4))],
+        Operator::LESSTHANEQUAL,
+        frac(1, 8),
+    );
+    let written = sut::write("decimals", &program);
+    assert!(written.contains("    x  obj  2.5\n    x  c  -1.75\n"));
+    assert!(written.contains("    unused  obj  0\n"));
+    assert!(written.contains("    RHS  c  0.125\n"));
+    let parsed = sut::parse::<Value>(&written).unwrap();
+    assert_eq!(program.objective_coeffs(), parsed.objective_coeffs());
+    assert_eq!(
+        program.functional_constraints(),
+        parsed.functional_constraints()
+    );
+}
+
+#[test]
+fn writes_solution_as_comments() {
+    let program = sut::parse::<Value>(PRODUCTION).unwrap();
+    let solution = program.solve::<MObjectiveValue>(&mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, solution.status);
+    let written = sut::write_solution("production", &program, &solution);
+    assert!(written.starts_with("* status: Optimal\n* objective: "));
+    assert!(written.contains("* x = 1.5\n* y = 2.5\n"));
+    assert_eq!(program, sut::parse::<Value>(&written).unwrap());
+}
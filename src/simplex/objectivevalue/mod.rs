@@ -12,14 +12,21 @@ pub trait ObjectiveValue<R: RowValue>:
     + Clone
     + Neg<Output = Self>
     + Add<Output = Self>
+    + Add<R, Output = Self>
     + Mul<R, Output = Self>
     + Div<R, Output = Self>
     + Display
     + Sized
 {
+    fn zero() -> Self;
     fn is_optimal(&self) -> bool;
+    /// The value without any penalty on artificial variables.
+    fn finite_part(&self) -> R;
+    /// The cost of one unit of an artificial variable in the maximized
+    /// objective, negated.
+    fn artificial_penalty() -> Self;
     fn initial_objective_equation(
         objective_fn_coeffs: &Coefficients<R>,
-        functional_constraints: &Vec<Constraint<R>>,
+        functional_constraints: &[Constraint<R>],
     ) -> Row<Self>;
 }
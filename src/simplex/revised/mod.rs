@@ -0,0 +1,269 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::{Row, RowValue};
+use crate::simplex::tabular::{
+    Problem, SimplexRow, SolveOptions, SolveResult, SolveStatus, Tableau,
+};
+use crate::simplex::{ObjectiveSense, Variable};
+
+/// The state of a revised simplex solve. The starting tableau of `problem`
+/// stays untouched as the constraint matrix; only the basis, its inverse
+/// and the values of the basic variables change. Columns are priced and
+/// computed from the inverse on demand, so a pivot updates an m×m inverse
+/// instead of every row of the tableau.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RevisedProblem<R: RowValue, O: ObjectiveValue<R>> {
+    /// The problem as built, before any pivots.
+    pub problem: Problem<R, O>,
+    pub basis: Vec<Variable>,
+    /// The inverse of the basis columns of the starting tableau.
+    pub basis_inverse: Vec<Vec<R>>,
+    /// The value of each row's basic variable.
+    pub values: Vec<R>,
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> RevisedProblem<R, O> {
+    /// Starts from the slack and artificial basis, whose columns form the
+    /// identity.
+    pub fn new(problem: Problem<R, O>) -> Self {
+        let row_count = problem.rows.len();
+        Self {
+            basis: problem.rows.iter().map(|row| row.basic_variable).collect(),
+            basis_inverse: (0..row_count)
+                .map(|i| {
+                    (0..row_count)
+                        .map(|k| if i == k { R::one() } else { R::zero() })
+                        .collect()
+                })
+                .collect(),
+            values: problem
+                .rows
+                .iter()
+                .map(|row| row.equation.constraint.clone())
+                .collect(),
+            problem,
+        }
+    }
+
+    /// The simplex multipliers: the starting objective row entries of the
+    /// basic columns times the basis inverse.
+    pub fn prices(&self) -> Vec<O> {
+        (0..self.values.len())
+            .map(|k| {
+                self.basis
+                    .iter()
+                    .zip(&self.basis_inverse)
+                    .map(|(var, inverse_row)| self.cost(*var) * inverse_row[k].clone())
+                    .reduce(|sum, term| sum + term)
+                    .expect("every row has a basic variable")
+            })
+            .collect()
+    }
+
+    /// The objective row entry `var` would have in the current tableau.
+    pub fn reduced_cost(&self, var: Variable, prices: &[O]) -> O {
+        prices
+            .iter()
+            .zip(&self.problem.rows)
+            .filter(|(_, row)| row.equation.coefficients[var] != R::zero())
+            .fold(self.cost(var), |cost, (price, row)| {
+                cost + -(price.clone() * row.equation.coefficients[var].clone())
+            })
+    }
+
+    /// The column `var` would have in the current tableau.
+    pub fn column(&self, var: Variable) -> Vec<R> {
+        self.basis_inverse
+            .iter()
+            .map(|inverse_row| {
+                inverse_row
+                    .iter()
+                    .zip(&self.problem.rows)
+                    .filter(|(_, row)| row.equation.coefficients[var] != R::zero())
+                    .fold(R::zero(), |sum, (inverse, row)| {
+                        sum + inverse.clone() * row.equation.coefficients[var].clone()
+                    })
+            })
+            .collect()
+    }
+
+    /// The full tableau for the current basis, as the tableau solver would
+    /// have it. Building it computes every column, so solves leave it to
+    /// the caller.
+    pub fn tableau(&self) -> Problem<R, O> {
+        let prices = self.prices();
+        let column_count = self.problem.point.len();
+        let columns: Vec<Vec<R>> = (0..column_count).map(|var| self.column(var)).collect();
+        let rows = self
+            .basis
+            .iter()
+            .zip(&self.values)
+            .enumerate()
+            .map(|(i, (var, value))| SimplexRow {
+                basic_variable: *var,
+                equation: Row {
+                    coefficients: columns.iter().map(|column| column[i].clone()).collect(),
+                    constraint: value.clone(),
+                },
+                ratio: R::zero(),
+            })
+            .collect();
+        let objective_value = self.objective_value(&prices);
+        let mut point = vec![R::zero(); column_count];
+        for (var, value) in self.basis.iter().zip(&self.values) {
+            point[*var] = value.clone();
+        }
+        Problem {
+            objective_equation: Row {
+                coefficients: (0..column_count)
+                    .map(|var| self.reduced_cost(var, &prices))
+                    .collect(),
+                constraint: objective_value,
+            },
+            rows,
+            point,
+            decision_var_count: self.problem.decision_var_count,
+            artificial_variables: self.problem.artificial_variables.clone(),
+            sense: self.problem.sense,
+        }
+    }
+
+    /// The objective row constant for the current basis.
+    fn objective_value(&self, prices: &[O]) -> O {
+        prices.iter().zip(&self.problem.rows).fold(
+            self.problem.objective_equation.constraint.clone(),
+            |value, (price, row)| value + -(price.clone() * row.equation.constraint.clone()),
+        )
+    }
+
+    fn cost(&self, var: Variable) -> O {
+        self.problem.objective_equation.coefficients[var].clone()
+    }
+
+    /// Most negative reduced cost among the nonbasic columns enters, as in
+    /// the tableau solver. An artificial variable never re-enters.
+    fn pivot_variable(&self, prices: &[O]) -> Option<Variable> {
+        (0..self.problem.point.len())
+            .filter(|var| {
+                !self.problem.artificial_variables.contains(var) && !self.basis.contains(var)
+            })
+            .map(|var| (var, self.reduced_cost(var, prices)))
+            .filter(|(_, cost)| !cost.is_optimal())
+            .min_by(|(_, c1), (_, c2)| c1.cmp(c2))
+            .unzip()
+            .0
+    }
+
+    /// Smallest ratio of value to a positive entry of the entering column.
+    fn pivot_row_idx(&self, column: &[R]) -> Option<usize> {
+        column
+            .iter()
+            .zip(&self.values)
+            .enumerate()
+            .filter(|(_, (coeff, _))| coeff.is_positive())
+            .map(|(idx, (coeff, value))| (idx, value.clone() / coeff.clone()))
+            .min_by(|(_, r1), (_, r2)| r1.cmp(r2))
+            .unzip()
+            .0
+    }
+
+    /// Updates the inverse and values by the row operations that would make
+    /// `column` the unit column of `pivot_row_idx`.
+    fn pivot(&mut self, pivot_row_idx: usize, var: Variable, column: &[R]) {
+        let pivot = column[pivot_row_idx].clone();
+        for inverse in &mut self.basis_inverse[pivot_row_idx] {
+            *inverse = inverse.clone() / pivot.clone();
+        }
+        self.values[pivot_row_idx] = self.values[pivot_row_idx].clone() / pivot;
+        let pivot_inverse = self.basis_inverse[pivot_row_idx].clone();
+        let pivot_value = self.values[pivot_row_idx].clone();
+        for (i, factor) in column.iter().enumerate() {
+            if i == pivot_row_idx || *factor == R::zero() {
+                continue;
+            }
+            for (inverse, pivot_inverse) in self.basis_inverse[i].iter_mut().zip(&pivot_inverse) {
+                *inverse = inverse.clone() + -(factor.clone() * pivot_inverse.clone());
+            }
+            self.values[i] = self.values[i].clone() + -(factor.clone() * pivot_value.clone());
+        }
+        self.basis[pivot_row_idx] = var;
+    }
+
+    /// The result for the current basis, read off the prices without
+    /// building the tableau.
+    fn result(self, status: SolveStatus, iterations: usize) -> SolveResult<R, O, Self> {
+        let mut result = SolveResult::from_problem(self, status, iterations);
+        if status == SolveStatus::Optimal && !result.infeasible_constraints.is_empty() {
+            result.status = SolveStatus::Infeasible;
+        }
+        result
+    }
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> Tableau<R, O> for RevisedProblem<R, O> {
+    fn sense(&self) -> ObjectiveSense {
+        self.problem.sense
+    }
+
+    fn decision_var_count(&self) -> usize {
+        self.problem.decision_var_count
+    }
+
+    fn artificial_variables(&self) -> &[Variable] {
+        &self.problem.artificial_variables
+    }
+
+    fn basic_values(&self) -> Vec<(Variable, R)> {
+        self.basis
+            .iter()
+            .copied()
+            .zip(self.values.iter().cloned())
+            .collect()
+    }
+
+    fn objective_constant(&self) -> O {
+        self.objective_value(&self.prices())
+    }
+
+    fn slack_objective_coefficients(&self) -> Vec<O> {
+        let prices = self.prices();
+        let first = self.problem.decision_var_count;
+        (first..first + prices.len())
+            .map(|var| self.reduced_cost(var, &prices))
+            .collect()
+    }
+}
+
+pub fn solve<R: RowValue, O: ObjectiveValue<R>>(
+    problem: Problem<R, O>,
+) -> SolveResult<R, O, RevisedProblem<R, O>> {
+    solve_with_options(problem, &SolveOptions::default())
+}
+
+/// Solves a problem as built by `Problem::new` or `Problem::with_sense`,
+/// following the same pivots as the tableau solver's Dantzig rule.
+pub fn solve_with_options<R: RowValue, O: ObjectiveValue<R>>(
+    problem: Problem<R, O>,
+    options: &SolveOptions,
+) -> SolveResult<R, O, RevisedProblem<R, O>> {
+    let mut revised = RevisedProblem::new(problem);
+    let mut iterations = 0;
+    loop {
+        let prices = revised.prices();
+        let Some(pivot_variable) = revised.pivot_variable(&prices) else {
+            break;
+        };
+        if iterations == options.iteration_limit {
+            return revised.result(SolveStatus::IterationLimit, iterations);
+        }
+        let column = revised.column(pivot_variable);
+        let Some(pivot_row_idx) = revised.pivot_row_idx(&column) else {
+            return revised.result(SolveStatus::Unbounded, iterations);
+        };
+        revised.pivot(pivot_row_idx, pivot_variable, &column);
+        iterations += 1;
+    }
+    revised.result(SolveStatus::Optimal, iterations)
+}
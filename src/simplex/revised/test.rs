@@ -0,0 +1,154 @@
+use crate::simplex::revised::{self as sut, RevisedProblem};
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::{EmptyObserver, SolveOptions, SolveResult, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value::{self, Value};
+use crate::simplex::ObjectiveSense;
+
+fn make_production_problem() -> PrimalProblem {
+    PrimalProblem::new(
+        &vec![frac(1, 1), frac(2, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+            upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(8, 1)),
+        ],
+    )
+}
+
+fn make_covering_problem() -> PrimalProblem {
+    PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &vec![frac(2, 1), frac(3, 1), frac(4, 1)],
+        &[
+            lower_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(4, 1)),
+            equality_constraint(vec![frac(1, 1), -frac(1, 1), value::zero()], value::zero()),
+            upper_bound_constraint(vec![frac(1, 1), value::zero(), frac(2, 1)], frac(5, 1)),
+        ],
+    )
+}
+
+fn assert_same_result(
+    expected: &SolveResult<Value, MObjectiveValue>,
+    actual: &SolveResult<Value, MObjectiveValue, RevisedProblem<Value, MObjectiveValue>>,
+) {
+    assert_eq!(expected.status, actual.status);
+    assert_eq!(expected.objective_value, actual.objective_value);
+    assert_eq!(expected.point, actual.point);
+    assert_eq!(expected.basis, actual.basis);
+    assert_eq!(expected.iterations, actual.iterations);
+    assert_eq!(
+        expected.infeasible_constraints,
+        actual.infeasible_constraints
+    );
+    assert_eq!(expected.dual_values, actual.dual_values);
+}
+
+#[test]
+fn starts_from_identity_basis() {
+    let revised = RevisedProblem::new(make_production_problem());
+    assert_eq!(vec![2, 3], revised.basis);
+    assert_eq!(
+        vec![
+            vec![frac(1, 1), value::zero()],
+            vec![value::zero(), frac(1, 1)]
+        ],
+        revised.basis_inverse
+    );
+    assert_eq!(vec![frac(4, 1), frac(8, 1)], revised.values);
+}
+
+#[test]
+fn prices_columns_from_basis_inverse() {
+    let mut revised = RevisedProblem::new(make_production_problem());
+    revised.basis = vec![2, 1];
+    revised.basis_inverse = vec![
+        vec![frac(1, 1), -frac(1, 3)],
+        vec![value::zero(), frac(1, 3)],
+    ];
+    revised.values = vec![frac(4, 3), frac(8, 3)];
+    let prices = revised.prices();
+    assert_eq!(
+        vec![
+            MObjectiveValue::from(value::zero()),
+            MObjectiveValue::from(-frac(2, 3))
+        ],
+        prices
+    );
+    assert_eq!(
+        MObjectiveValue::from(-frac(1, 3)),
+        revised.reduced_cost(0, &prices)
+    );
+    assert_eq!(vec![frac(2, 3), frac(1, 3)], revised.column(0));
+    let tableau = revised.tableau();
+    assert_eq!(
+        MObjectiveValue::from(frac(16, 3)),
+        tableau.objective_equation.constraint
+    );
+    assert_eq!(
+        vec![value::zero(), frac(8, 3), frac(4, 3), value::zero()],
+        tableau.point
+    );
+}
+
+#[test]
+fn matches_tableau_solver() {
+    for problem in [make_production_problem(), make_covering_problem()] {
+        let expected = primal::solve(problem.clone(), &mut EmptyObserver {});
+        let result = sut::solve(problem);
+        assert_eq!(SolveStatus::Optimal, result.status);
+        assert_same_result(&expected, &result);
+        assert_eq!(
+            expected.tableau.objective_equation,
+            result.tableau.tableau().objective_equation
+        );
+    }
+}
+
+#[test]
+fn reads_result_off_the_basis_it_stopped_at() {
+    let result = sut::solve(make_covering_problem());
+    assert_eq!(MObjectiveValue::from(frac(10, 1)), result.objective_value);
+    assert_eq!(
+        vec![frac(5, 2), -frac(1, 2), value::zero()],
+        result.dual_values
+    );
+    assert_eq!(result.basis, result.tableau.basis);
+}
+
+#[test]
+fn reports_infeasible_and_unbounded_problems() {
+    let infeasible = PrimalProblem::new(
+        &vec![frac(1, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1)], frac(1, 1)),
+            lower_bound_constraint(vec![frac(1, 1)], frac(2, 1)),
+        ],
+    );
+    let result = sut::solve(infeasible);
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.infeasible_constraints);
+    let unbounded = PrimalProblem::new(
+        &vec![frac(1, 1), value::zero()],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), -frac(1, 1)],
+            frac(1, 1),
+        )],
+    );
+    let expected = primal::solve(unbounded.clone(), &mut EmptyObserver {});
+    let result = sut::solve(unbounded);
+    assert_eq!(SolveStatus::Unbounded, result.status);
+    assert_same_result(&expected, &result);
+}
+
+#[test]
+fn stops_at_iteration_limit() {
+    let options = SolveOptions { iteration_limit: 1 };
+    let expected =
+        primal::solve_with_options(make_covering_problem(), &options, &mut EmptyObserver {});
+    let result = sut::solve_with_options(make_covering_problem(), &options);
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_same_result(&expected, &result);
+}
@@ -17,6 +17,27 @@ pub trait RowValue:
     fn zero() -> Self;
     fn one() -> Self;
     fn is_finite(&self) -> bool;
+
+    /// The value of `decimal`, or `None` if the type cannot hold it.
+    fn from_decimal(decimal: &Decimal) -> Option<Self>;
+
+    /// Whether the value counts as positive in ratio and feasibility
+    /// checks. Inexact types may treat values near zero as zero.
+    fn is_positive(&self) -> bool {
+        *self > Self::zero()
+    }
+
+    /// Whether the value counts as negative in ratio and feasibility
+    /// checks.
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+
+    /// Whether an objective row entry of this value is negative enough for
+    /// its column to improve the objective.
+    fn is_improving(&self) -> bool {
+        self.is_negative()
+    }
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -24,3 +45,44 @@ pub struct Row<R> {
     pub coefficients: Vec<R>,
     pub constraint: R,
 }
+
+/// A decimal number such as `12`, `-0.25` or `1.5e3`, split into its
+/// digits and a power of ten, so `-0.25` is `-25e-2`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Decimal {
+    pub negative: bool,
+    pub digits: String,
+    pub exponent: i32,
+}
+
+impl Decimal {
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, magnitude) = match text.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if !magnitude.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return None;
+        }
+        let (mantissa, exponent) = match magnitude.find(['e', 'E']) {
+            Some(idx) => (&magnitude[..idx], magnitude[idx + 1..].parse::<i32>().ok()?),
+            None => (magnitude, 0),
+        };
+        let (whole, decimals) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = format!("{}{}", whole, decimals);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        Some(Self {
+            negative,
+            digits,
+            exponent: exponent.checked_sub(i32::try_from(decimals.len()).ok()?)?,
+        })
+    }
+}
+
+/// Parses a decimal number as any row value type, exactly when the type is
+/// exact.
+pub fn parse_decimal<R: RowValue>(text: &str) -> Option<R> {
+    R::from_decimal(&Decimal::parse(text)?)
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::{Coefficients, Constraint, Operator};
+
+/// Coefficients stored as `(index, value)` pairs in index order, leaving
+/// out zeros. `len` is the length of the dense vector they stand for.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SparseCoefficients<R: RowValue> {
+    pub len: usize,
+    pub entries: Vec<(usize, R)>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SparseConstraint<R: RowValue> {
+    pub operator: Operator,
+    pub coefficients: SparseCoefficients<R>,
+    pub bound: R,
+}
+
+impl<R: RowValue> SparseCoefficients<R> {
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            entries: vec![],
+        }
+    }
+
+    /// Sorts the entries, summing repeated indices and dropping zeros.
+    pub fn from_entries(len: usize, mut entries: Vec<(usize, R)>) -> Self {
+        entries.sort_by_key(|(idx, _)| *idx);
+        let mut merged: Vec<(usize, R)> = vec![];
+        for (idx, value) in entries {
+            match merged.last_mut() {
+                Some((last_idx, sum)) if *last_idx == idx => *sum = sum.clone() + value,
+                _ => merged.push((idx, value)),
+            }
+        }
+        merged.retain(|(_, value)| *value != R::zero());
+        Self {
+            len,
+            entries: merged,
+        }
+    }
+
+    pub fn from_dense(coefficients: &[R]) -> Self {
+        Self {
+            len: coefficients.len(),
+            entries: coefficients
+                .iter()
+                .cloned()
+                .enumerate()
+                .filter(|(_, value)| *value != R::zero())
+                .collect(),
+        }
+    }
+
+    pub fn to_dense(&self) -> Coefficients<R> {
+        let mut coefficients = vec![R::zero(); self.len];
+        for (idx, value) in &self.entries {
+            coefficients[*idx] = value.clone();
+        }
+        coefficients
+    }
+
+    pub fn get(&self, idx: usize) -> R {
+        match self
+            .entries
+            .binary_search_by_key(&idx, |(entry_idx, _)| *entry_idx)
+        {
+            Ok(pos) => self.entries[pos].1.clone(),
+            Err(_) => R::zero(),
+        }
+    }
+
+    /// Appends an entry past the last one, growing `len` to fit it.
+    pub fn push(&mut self, idx: usize, value: R) {
+        debug_assert!(self.entries.last().is_none_or(|(last, _)| *last < idx));
+        if value != R::zero() {
+            self.entries.push((idx, value));
+        }
+        self.len = self.len.max(idx + 1);
+    }
+
+    pub fn divide(&mut self, divisor: &R) {
+        for (_, value) in &mut self.entries {
+            *value = value.clone() / divisor.clone();
+        }
+    }
+
+    /// Adds `factor * other` by merging the two entry lists. Entries that
+    /// cancel out are dropped.
+    pub fn add_scaled(&mut self, factor: &R, other: &SparseCoefficients<R>) {
+        let mut merged = Vec::with_capacity(self.entries.len() + other.entries.len());
+        let mut own = std::mem::take(&mut self.entries).into_iter().peekable();
+        let mut added = other.entries.iter().peekable();
+        loop {
+            let entry = match (own.peek(), added.peek()) {
+                (Some((own_idx, _)), Some((added_idx, _))) if own_idx == added_idx => {
+                    let (idx, value) = own.next().unwrap();
+                    let (_, added_value) = added.next().unwrap();
+                    (idx, value + factor.clone() * added_value.clone())
+                }
+                (Some((own_idx, _)), Some((added_idx, _))) if added_idx < own_idx => {
+                    let (idx, added_value) = added.next().unwrap();
+                    (*idx, factor.clone() * added_value.clone())
+                }
+                (Some(_), _) => own.next().unwrap(),
+                (None, Some(_)) => {
+                    let (idx, added_value) = added.next().unwrap();
+                    (*idx, factor.clone() * added_value.clone())
+                }
+                (None, None) => break,
+            };
+            if entry.1 != R::zero() {
+                merged.push(entry);
+            }
+        }
+        self.entries = merged;
+        self.len = self.len.max(other.len);
+    }
+}
+
+impl<R: RowValue> SparseConstraint<R> {
+    pub fn to_dense(&self) -> Constraint<R> {
+        Constraint {
+            operator: self.operator.clone(),
+            coefficients: self.coefficients.to_dense(),
+            bound: self.bound.clone(),
+        }
+    }
+}
+
+impl<R: RowValue> From<&Constraint<R>> for SparseConstraint<R> {
+    fn from(constraint: &Constraint<R>) -> Self {
+        Self {
+            operator: constraint.operator.clone(),
+            coefficients: SparseCoefficients::from_dense(&constraint.coefficients),
+            bound: constraint.bound.clone(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::simplex::sparse::{self as sut, SparseCoefficients, SparseConstraint};
+use crate::simplex::test::{frac, lower_bound_constraint};
+use crate::simplex::value::{self, Value};
+use crate::simplex::Operator;
+
+fn sparse(len: usize, entries: &[(usize, Value)]) -> SparseCoefficients<Value> {
+    SparseCoefficients {
+        len,
+        entries: entries.to_vec(),
+    }
+}
+
+#[test]
+fn sorts_and_merges_entries() {
+    let coefficients = SparseCoefficients::from_entries(
+        5,
+        vec![
+            (3, frac(1, 1)),
+            (0, frac(2, 1)),
+            (3, frac(1, 2)),
+            (1, frac(1, 1)),
+            (1, -frac(1, 1)),
+        ],
+    );
+    assert_eq!(sparse(5, &[(0, frac(2, 1)), (3, frac(3, 2))]), coefficients);
+}
+
+#[test]
+fn converts_to_and_from_dense() {
+    let dense = vec![value::zero(), frac(4, 1), value::zero(), -frac(1, 2)];
+    let coefficients = SparseCoefficients::from_dense(&dense);
+    assert_eq!(
+        sparse(4, &[(1, frac(4, 1)), (3, -frac(1, 2))]),
+        coefficients
+    );
+    assert_eq!(dense, coefficients.to_dense());
+    assert_eq!(frac(4, 1), coefficients.get(1));
+    assert_eq!(value::zero(), coefficients.get(2));
+}
+
+#[test]
+fn pushes_entries_past_the_end() {
+    let mut coefficients = sut::SparseCoefficients::new(2);
+    coefficients.push(1, frac(1, 1));
+    coefficients.push(3, value::zero());
+    coefficients.push(4, -frac(1, 1));
+    assert_eq!(
+        sparse(5, &[(1, frac(1, 1)), (4, -frac(1, 1))]),
+        coefficients
+    );
+}
+
+#[test]
+fn adds_scaled_coefficients_and_drops_cancelled_entries() {
+    let mut coefficients = sparse(5, &[(0, frac(1, 1)), (2, frac(2, 1)), (4, frac(1, 1))]);
+    let other = sparse(5, &[(1, frac(1, 1)), (2, frac(1, 1)), (3, frac(3, 1))]);
+    coefficients.add_scaled(&-frac(2, 1), &other);
+    assert_eq!(
+        sparse(
+            5,
+            &[
+                (0, frac(1, 1)),
+                (1, -frac(2, 1)),
+                (3, -frac(6, 1)),
+                (4, frac(1, 1))
+            ]
+        ),
+        coefficients
+    );
+    coefficients.divide(&frac(2, 1));
+    assert_eq!(-frac(3, 1), coefficients.get(3));
+}
+
+#[test]
+fn converts_constraints() {
+    let constraint = lower_bound_constraint(vec![value::zero(), frac(2, 1)], frac(3, 1));
+    let sparse_constraint = SparseConstraint::from(&constraint);
+    assert_eq!(Operator::GREATERTHANEQUAL, sparse_constraint.operator);
+    assert_eq!(
+        sparse(2, &[(1, frac(2, 1))]),
+        sparse_constraint.coefficients
+    );
+    assert_eq!(constraint, sparse_constraint.to_dense());
+}
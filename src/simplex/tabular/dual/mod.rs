@@ -1,2 +1,104 @@
 #[cfg(test)]
 mod test;
+
+use super::{Problem, ProblemObserver, SolveOptions, SolveResult, SolveStatus};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::{self, primal};
+use crate::simplex::{Constraint, Variable};
+
+/// Solves a problem whose tableau is dual feasible (every objective row
+/// coefficient is optimal) but may be primal infeasible (negative right-hand
+/// sides), such as an optimal tableau whose bounds were just tightened.
+pub fn solve<R: RowValue, O: ObjectiveValue<R>>(
+    problem: Problem<R, O>,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    solve_with_options(problem, &SolveOptions::default(), observer)
+}
+
+/// Once the rows are feasible the primal solver takes over, so a tableau
+/// that was not dual feasible after all still ends at the optimum, and one
+/// that ends with an artificial variable basic at a positive level is
+/// reported infeasible.
+pub fn solve_with_options<R: RowValue, O: ObjectiveValue<R>>(
+    mut problem: Problem<R, O>,
+    options: &SolveOptions,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    let mut iterations = 0;
+    while !is_feasible(&problem) {
+        if iterations == options.iteration_limit {
+            return SolveResult::from_problem(problem, SolveStatus::IterationLimit, iterations);
+        }
+        let Some(pivot_row_idx) = pivot_row_idx(&problem) else {
+            break;
+        };
+        let Some(pivot_variable) = pivot_variable(&problem, pivot_row_idx) else {
+            return SolveResult::from_problem(problem, SolveStatus::Infeasible, iterations);
+        };
+        observer.observe(problem.clone());
+        tabular::pivot(&mut problem, pivot_row_idx, pivot_variable);
+        iterations += 1;
+    }
+    let remaining = SolveOptions {
+        iteration_limit: options.iteration_limit - iterations,
+    };
+    let mut result = primal::solve_with_options(problem, &remaining, observer);
+    result.iterations += iterations;
+    result
+}
+
+/// Adds `constraints` to a problem solved to optimality and continues from
+/// its basis, instead of rebuilding the problem and starting again from the
+/// slack basis. The added rows follow the existing ones in the result.
+pub fn reoptimize<R: RowValue, O: ObjectiveValue<R>>(
+    mut problem: Problem<R, O>,
+    constraints: &[Constraint<R>],
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    for constraint in constraints {
+        problem.add_constraint(constraint);
+    }
+    solve(problem, observer)
+}
+
+fn is_feasible<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> bool {
+    problem
+        .rows
+        .iter()
+        .all(|row| !row.equation.constraint.is_negative())
+}
+
+fn pivot_row_idx<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> Option<usize> {
+    problem
+        .rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.equation.constraint.is_negative())
+        .min_by(|(_, r1), (_, r2)| r1.equation.constraint.cmp(&r2.equation.constraint))
+        .unzip()
+        .0
+}
+
+/// Smallest dual ratio among the negative entries of the pivot row. An
+/// artificial variable never re-enters once it has left.
+fn pivot_variable<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    pivot_row_idx: usize,
+) -> Option<Variable> {
+    let pivot_row = &problem.rows[pivot_row_idx].equation;
+    problem
+        .objective_equation
+        .coefficients
+        .iter()
+        .zip(pivot_row.coefficients.iter())
+        .enumerate()
+        .filter(|(var, (_, coeff))| {
+            !problem.artificial_variables.contains(var) && coeff.is_negative()
+        })
+        .map(|(var, (obj_coeff, coeff))| (var, obj_coeff.clone() / -coeff.clone()))
+        .min_by(|(_, ratio1), (_, ratio2)| ratio1.cmp(ratio2))
+        .unzip()
+        .0
+}
@@ -1,4 +1,290 @@
-use crate::simplex::{
-    tabular::dual::{self as sut},
+use crate::simplex::tabular::dual::{self as sut};
+use crate::simplex::tabular::primal::mobjectivevalue::test::mvalue_from;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::twophase::{self, TwoPhaseProblem};
+use crate::simplex::tabular::primal::{self, MObjectiveEquation, PrimalProblem};
+use crate::simplex::tabular::{
+    self, EmptyObserver, Equation, SimplexRow, SolveOptions, SolveStatus,
 };
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, tableau, upper_bound_constraint,
+};
+use crate::simplex::value::Value;
+use crate::simplex::{Constraint, ObjectiveSense};
+
+// minimize 2x0 + 3x1 subject to x0 + x1 >= 4 and x0 + 3x1 >= 6,
+// written with negated >= rows so the slack basis is dual feasible.
+fn make_covering_problem() -> PrimalProblem {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![-frac(1, 1), -frac(1, 1)], -frac(4, 1)),
+        upper_bound_constraint(vec![-frac(1, 1), -frac(3, 1)], -frac(6, 1)),
+    ];
+    PrimalProblem::with_sense(ObjectiveSense::Minimize, &objective_function, &fn_constraints)
+}
+
+fn make_single_row_problem(coefficients: Vec<Value>, constraint: Value) -> PrimalProblem {
+    tableau(
+        MObjectiveEquation {
+            coefficients: vec![mvalue_from(2, 1), mvalue_from(3, 1), mvalue_from(0, 1)],
+            constraint: mvalue_from(0, 1),
+        },
+        vec![SimplexRow {
+            basic_variable: 2,
+            equation: Equation {
+                coefficients,
+                constraint,
+            },
+            ratio: frac(0, 1),
+        }],
+        vec![],
+    )
+}
+
+#[test]
+fn solves_dual_feasible_problem() {
+    let result = sut::solve(make_covering_problem(), &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], result.point);
+    assert_eq!(vec![0, 1], result.basis);
+    assert_eq!(2, result.iterations);
+    assert_eq!(MObjectiveValue::from(frac(9, 1)), result.objective_value);
+}
+
+#[test]
+fn leaves_feasible_problem_unchanged() {
+    let objective_function = vec![-frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(4, 1))];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(0, 1)], result.point);
+    assert_eq!(0, result.iterations);
+}
+
+#[test]
+fn finishes_with_primal_pivots_when_objective_row_is_not_optimal() {
+    let objective_function = vec![frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(4, 1))];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(4, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(4, 1)), result.objective_value);
+    assert_eq!(1, result.iterations);
+}
+
+#[test]
+fn reports_infeasible_when_no_entering_variable_exists() {
+    let objective_function = vec![-frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(vec![frac(1, 1)], -frac(4, 1))];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.basis);
+}
+
+#[test]
+fn stops_at_iteration_limit() {
+    let options = SolveOptions { iteration_limit: 1 };
+    let result =
+        sut::solve_with_options(make_covering_problem(), &options, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(1, result.iterations);
+}
+
+#[test]
+fn reaches_optimal_objective_value() {
+    let mut problem = make_covering_problem();
+    while let Some(row_idx) = sut::pivot_row_idx(&problem) {
+        let var = sut::pivot_variable(&problem, row_idx).unwrap();
+        tabular::pivot(&mut problem, row_idx, var);
+    }
+    assert!(sut::is_feasible(&problem));
+    assert_eq!(
+        -MObjectiveValue::from(frac(9, 1)),
+        problem.objective_equation.constraint
+    );
+}
+
+#[test]
+fn picks_most_negative_right_hand_side() {
+    let problem = make_covering_problem();
+    assert_eq!(Some(1), sut::pivot_row_idx(&problem));
+}
+
+#[test]
+fn has_no_pivot_row_when_feasible() {
+    let problem = make_single_row_problem(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(2, 1));
+    assert_eq!(None, sut::pivot_row_idx(&problem));
+}
+
+#[test]
+fn picks_entering_variable_by_dual_ratio() {
+    let problem = make_single_row_problem(vec![-frac(1, 1), -frac(3, 1), frac(1, 1)], -frac(6, 1));
+    assert_eq!(Some(1), sut::pivot_variable(&problem, 0));
+}
+
+#[test]
+fn ignores_non_negative_row_coefficients() {
+    let problem = make_single_row_problem(vec![-frac(1, 1), frac(3, 1), frac(1, 1)], -frac(6, 1));
+    assert_eq!(Some(0), sut::pivot_variable(&problem, 0));
+}
+
+#[test]
+fn has_no_entering_variable_without_negative_coefficients() {
+    let problem = make_single_row_problem(vec![frac(1, 1), frac(3, 1), frac(1, 1)], -frac(6, 1));
+    assert_eq!(None, sut::pivot_variable(&problem, 0));
+}
+
+// maximize 3x0 + 2x1 subject to x0 + x1 <= 4 and x0 + 3x1 <= 6, solved to
+// x0 = 4, x1 = 0.
+fn make_solved_production_problem() -> PrimalProblem {
+    let objective_function = vec![frac(3, 1), frac(2, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+        upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(6, 1)),
+    ];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    primal::solve(problem, &mut EmptyObserver::new()).tableau
+}
+
+#[test]
+fn reoptimizes_after_adding_violated_constraint() {
+    let constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(3, 1))];
+    let result = sut::reoptimize(
+        make_solved_production_problem(),
+        &constraints,
+        &mut EmptyObserver::new(),
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(11, 1)), result.objective_value);
+    assert_eq!(1, result.iterations);
+}
+
+#[test]
+fn matches_solve_from_scratch() {
+    let objective_function = vec![frac(3, 1), frac(2, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+        upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(6, 1)),
+        lower_bound_constraint(vec![frac(0, 1), frac(1, 1)], frac(1, 1)),
+    ];
+    let from_scratch = primal::solve(
+        PrimalProblem::new(&objective_function, &fn_constraints),
+        &mut EmptyObserver::new(),
+    );
+    let result = sut::reoptimize(
+        make_solved_production_problem(),
+        &fn_constraints[2..],
+        &mut EmptyObserver::new(),
+    );
+    assert_eq!(from_scratch.status, result.status);
+    assert_eq!(from_scratch.point, result.point);
+    assert_eq!(from_scratch.objective_value, result.objective_value);
+}
+
+#[test]
+fn reoptimizes_after_adding_equality_constraint() {
+    let constraints = vec![equality_constraint(vec![frac(1, 1)], frac(2, 1))];
+    let result = sut::reoptimize(
+        make_solved_production_problem(),
+        &constraints,
+        &mut EmptyObserver::new(),
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(2, 1), frac(4, 3)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(26, 3)), result.objective_value);
+}
+
+#[test]
+fn keeps_optimal_basis_for_satisfied_constraint() {
+    let constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(10, 1))];
+    let result = sut::reoptimize(
+        make_solved_production_problem(),
+        &constraints,
+        &mut EmptyObserver::new(),
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(4, 1), frac(0, 1)], result.point);
+    assert_eq!(0, result.iterations);
+}
+
+#[test]
+fn reoptimizes_problem_that_was_not_solved() {
+    let objective_function = vec![frac(3, 1), frac(2, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+        upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(6, 1)),
+    ];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(3, 1))];
+    let result = sut::reoptimize(problem, &constraints, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(11, 1)), result.objective_value);
+}
+
+#[test]
+fn reports_infeasible_added_constraint() {
+    let constraints = vec![lower_bound_constraint(vec![frac(1, 1)], frac(5, 1))];
+    let result = sut::reoptimize(
+        make_solved_production_problem(),
+        &constraints,
+        &mut EmptyObserver::new(),
+    );
+    assert_eq!(SolveStatus::Infeasible, result.status);
+}
+
+// minimize x0 subject to x0 >= 1 and x0 + x1 <= 5, which leaves an
+// artificial column nonbasic at the optimum x0 = 1.
+fn make_artificial_constraints() -> Vec<Constraint<Value>> {
+    vec![
+        lower_bound_constraint(vec![frac(1, 1), frac(0, 1)], frac(1, 1)),
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(5, 1)),
+    ]
+}
+
+#[test]
+fn reports_infeasible_instead_of_reentering_artificial_with_big_m() {
+    let objective_function = vec![frac(1, 1), frac(0, 1)];
+    let problem = PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &make_artificial_constraints(),
+    );
+    let solved = primal::solve(problem, &mut EmptyObserver::new()).tableau;
+    let constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(0, 1))];
+    let result = sut::reoptimize(solved, &constraints, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert!(!result.basis.contains(&2));
+}
+
+#[test]
+fn reports_infeasible_instead_of_reentering_artificial_after_two_phase() {
+    let objective_function = vec![frac(1, 1), frac(0, 1)];
+    let problem = TwoPhaseProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &make_artificial_constraints(),
+    );
+    let solved = twophase::solve(problem, &mut EmptyObserver::new()).tableau;
+    let constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(0, 1))];
+    let result = sut::reoptimize(solved, &constraints, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert!(!result.basis.contains(&2));
+}
 
+#[test]
+fn reports_infeasible_when_artificial_stays_basic() {
+    let objective_function = vec![frac(1, 1)];
+    let fn_constraints = vec![
+        equality_constraint(vec![frac(1, 1)], frac(2, 1)),
+        upper_bound_constraint(vec![frac(1, 1)], frac(1, 1)),
+    ];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![0], result.infeasible_constraints);
+}
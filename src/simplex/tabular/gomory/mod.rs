@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod test;
+
+use super::{Problem, ProblemObserver, SolveOptions, SolveResult, SolveStatus};
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::{dual, primal};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, Operator};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct CutOptions {
+    /// How many times to add cuts and re-optimize.
+    pub round_limit: usize,
+    /// The iteration limit of each primal or dual solve.
+    pub iteration_limit: usize,
+}
+
+impl Default for CutOptions {
+    fn default() -> Self {
+        Self {
+            round_limit: 50,
+            iteration_limit: 10_000,
+        }
+    }
+}
+
+/// The Gomory fractional cut of a row whose basic variable has a fractional
+/// value. Writing the row as `x_B + sum(a_j x_j) = b`, every integer
+/// solution satisfies `sum(frac(a_j) x_j) >= frac(b)`. The cut covers every
+/// column of `problem`, with zeros on the basic and artificial columns.
+///
+/// The cut is only valid when every column takes integer values in every
+/// integer solution, as when all decision variables are integer and the
+/// constraints have integer coefficients and bounds.
+pub fn cut<O: ObjectiveValue<Value>>(
+    problem: &Problem<Value, O>,
+    row_idx: usize,
+) -> Option<Constraint<Value>> {
+    let equation = &problem.rows[row_idx].equation;
+    let bound = fractional_part(equation.constraint);
+    if bound == value::zero() {
+        return None;
+    }
+    Some(Constraint {
+        operator: Operator::GREATERTHANEQUAL,
+        coefficients: equation
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(var, coeff)| {
+                if problem.artificial_variables.contains(&var) {
+                    value::zero()
+                } else {
+                    fractional_part(*coeff)
+                }
+            })
+            .collect(),
+        bound,
+    })
+}
+
+/// Appends the cut of every row with a fractional basic value as a new row
+/// with its own slack column, returning how many were added. The new rows
+/// start with negative values, for the dual simplex to repair.
+pub fn add_cuts<O: ObjectiveValue<Value>>(problem: &mut Problem<Value, O>) -> usize {
+    let mut added = 0;
+    for row_idx in 0..problem.rows.len() {
+        let Some(cut) = cut(problem, row_idx) else {
+            continue;
+        };
+        problem.add_constraint(&cut);
+        added += 1;
+    }
+    added
+}
+
+/// Rewrites a cut over the columns of `initial`, the problem as built and
+/// before any pivots, in terms of its decision variables alone. Each slack
+/// column is `b - a·x` and each surplus column `a·x - b` for the bound `b`
+/// and coefficients `a` of its row.
+pub fn in_decision_variables<R: RowValue, O: ObjectiveValue<R>>(
+    initial: &Problem<R, O>,
+    cut: &Constraint<R>,
+) -> Constraint<R> {
+    let decision_var_count = initial.decision_var_count;
+    let mut coefficients = cut.coefficients[..decision_var_count].to_vec();
+    let mut bound = cut.bound.clone();
+    for (idx, row) in initial.rows.iter().enumerate() {
+        let slack = decision_var_count + idx;
+        let (column, sign) = if !initial.artificial_variables.contains(&slack) {
+            (slack, R::one())
+        } else {
+            let surplus = (decision_var_count + initial.rows.len()
+                ..row.equation.coefficients.len())
+                .find(|var| row.equation.coefficients[*var] != R::zero());
+            match surplus {
+                Some(surplus) => (surplus, -R::one()),
+                None => continue,
+            }
+        };
+        let factor = cut.coefficients[column].clone() * sign;
+        if factor == R::zero() {
+            continue;
+        }
+        for (coeff, row_coeff) in coefficients.iter_mut().zip(&row.equation.coefficients) {
+            *coeff = coeff.clone() + -(factor.clone() * row_coeff.clone());
+        }
+        bound = bound + -(factor * row.equation.constraint.clone());
+    }
+    Constraint {
+        operator: cut.operator.clone(),
+        coefficients,
+        bound,
+    }
+}
+
+pub fn solve<O: ObjectiveValue<Value>>(
+    problem: Problem<Value, O>,
+    observer: &mut impl ProblemObserver<Value, O>,
+) -> SolveResult<Value, O> {
+    solve_with_options(problem, &CutOptions::default(), observer)
+}
+
+/// Solves the relaxation with the primal simplex, then adds a round of cuts
+/// and re-optimizes with the dual simplex until the point is integral. The
+/// result stops at `IterationLimit` when the rounds run out first. Cuts are
+/// only valid for problems where every column is integer, as `cut`
+/// describes.
+pub fn solve_with_options<O: ObjectiveValue<Value>>(
+    problem: Problem<Value, O>,
+    options: &CutOptions,
+    observer: &mut impl ProblemObserver<Value, O>,
+) -> SolveResult<Value, O> {
+    let solve_options = SolveOptions {
+        iteration_limit: options.iteration_limit,
+    };
+    let mut result = primal::solve_with_options(problem, &solve_options, observer);
+    let mut iterations = result.iterations;
+    let mut rounds = 0;
+    while result.status == SolveStatus::Optimal {
+        let mut problem = result.tableau.clone();
+        if add_cuts(&mut problem) == 0 {
+            break;
+        }
+        if rounds == options.round_limit {
+            result.status = SolveStatus::IterationLimit;
+            break;
+        }
+        result = dual::solve_with_options(problem, &solve_options, observer);
+        iterations += result.iterations;
+        rounds += 1;
+    }
+    result.iterations = iterations;
+    result
+}
+
+/// `v - floor(v)`, which lies in `[0, 1)` for negative values too.
+fn fractional_part(v: Value) -> Value {
+    v + -v.floor()
+}
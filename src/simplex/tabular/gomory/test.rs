@@ -0,0 +1,172 @@
+use crate::simplex::tabular::gomory::{self as sut};
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::{EmptyObserver, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, ObjectiveSense, Operator};
+
+// maximize x + y subject to 3x + 2y <= 12 and 2x + 3y <= 12, whose
+// relaxation is optimal at x = y = 12/5.
+fn make_problem() -> PrimalProblem {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![frac(3, 1), frac(2, 1)], frac(12, 1)),
+        upper_bound_constraint(vec![frac(2, 1), frac(3, 1)], frac(12, 1)),
+    ];
+    PrimalProblem::new(&objective_function, &fn_constraints)
+}
+
+fn solved_problem() -> PrimalProblem {
+    primal::solve(make_problem(), &mut EmptyObserver::new()).tableau
+}
+
+fn row_of(problem: &PrimalProblem, var: usize) -> usize {
+    problem
+        .rows
+        .iter()
+        .position(|row| row.basic_variable == var)
+        .unwrap()
+}
+
+fn is_integral(point: &[Value]) -> bool {
+    point.iter().all(|value| value.fract() == value::zero())
+}
+
+#[test]
+fn reads_cut_off_fractional_row() {
+    let problem = solved_problem();
+    let cut = sut::cut(&problem, row_of(&problem, 0));
+    assert_eq!(
+        Some(Constraint {
+            operator: Operator::GREATERTHANEQUAL,
+            coefficients: vec![frac(0, 1), frac(0, 1), frac(3, 5), frac(3, 5)],
+            bound: frac(2, 5),
+        }),
+        cut
+    );
+}
+
+#[test]
+fn has_no_cut_for_integral_row() {
+    let objective_function = vec![frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(vec![frac(1, 1)], frac(3, 1))];
+    let problem = primal::solve(
+        PrimalProblem::new(&objective_function, &fn_constraints),
+        &mut EmptyObserver::new(),
+    )
+    .tableau;
+    assert_eq!(None, sut::cut(&problem, 0));
+}
+
+#[test]
+fn adds_cut_rows_with_negative_values() {
+    let mut problem = solved_problem();
+    assert_eq!(2, sut::add_cuts(&mut problem));
+    assert_eq!(4, problem.rows.len());
+    assert_eq!(4, problem.rows[2].basic_variable);
+    assert_eq!(-frac(2, 5), problem.rows[2].equation.constraint);
+    assert_eq!(-frac(2, 5), problem.rows[3].equation.constraint);
+}
+
+#[test]
+fn rewrites_cut_in_decision_variables() {
+    let problem = solved_problem();
+    let cut = sut::cut(&problem, row_of(&problem, 0)).unwrap();
+    assert_eq!(
+        Constraint {
+            operator: Operator::GREATERTHANEQUAL,
+            coefficients: vec![-frac(3, 1), -frac(3, 1)],
+            bound: -frac(14, 1),
+        },
+        sut::in_decision_variables(&make_problem(), &cut)
+    );
+}
+
+#[test]
+fn rewrites_surplus_column_in_decision_variables() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![lower_bound_constraint(
+        vec![frac(2, 1), frac(2, 1)],
+        frac(3, 1),
+    )];
+    let initial = PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &fn_constraints,
+    );
+    let cut = Constraint {
+        operator: Operator::GREATERTHANEQUAL,
+        coefficients: vec![frac(0, 1), frac(0, 1), frac(0, 1), frac(1, 2)],
+        bound: frac(1, 2),
+    };
+    assert_eq!(
+        Constraint {
+            operator: Operator::GREATERTHANEQUAL,
+            coefficients: vec![frac(1, 1), frac(1, 1)],
+            bound: frac(2, 1),
+        },
+        sut::in_decision_variables(&initial, &cut)
+    );
+}
+
+#[test]
+fn solves_to_integer_optimum() {
+    let result = sut::solve(make_problem(), &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(4, 1)), result.objective_value);
+    assert!(is_integral(&result.point));
+}
+
+#[test]
+fn solves_minimization_with_surplus_columns() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![lower_bound_constraint(
+        vec![frac(2, 1), frac(2, 1)],
+        frac(3, 1),
+    )];
+    let problem = PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &fn_constraints,
+    );
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(2, 1)), result.objective_value);
+    assert!(is_integral(&result.point));
+}
+
+#[test]
+fn reports_infeasible_without_integer_point() {
+    let objective_function = vec![frac(1, 1)];
+    let fn_constraints = vec![equality_constraint(vec![frac(2, 1)], frac(1, 1))];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+}
+
+#[test]
+fn reports_infeasible_when_relaxation_has_no_integer_point() {
+    // 10x >= 5 and 10x <= 7 hold for 1/2 <= x <= 7/10 only.
+    let objective_function = vec![frac(1, 1)];
+    let fn_constraints = vec![
+        lower_bound_constraint(vec![frac(10, 1)], frac(5, 1)),
+        upper_bound_constraint(vec![frac(10, 1)], frac(7, 1)),
+    ];
+    let problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+}
+
+#[test]
+fn stops_when_rounds_run_out() {
+    let options = sut::CutOptions {
+        round_limit: 0,
+        ..Default::default()
+    };
+    let result = sut::solve_with_options(make_problem(), &options, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(vec![frac(12, 5), frac(12, 5)], result.point);
+}
@@ -2,14 +2,17 @@
 mod test;
 
 pub mod dual;
+pub mod gomory;
 pub mod primal;
+pub mod sensitivity;
+pub mod sparse;
 pub mod write_observer;
 
 use std::ops::{Add, Mul, Neg};
 
 use crate::simplex::objectivevalue::ObjectiveValue;
 use crate::simplex::rowvalue::{Row, RowValue};
-use crate::simplex::{Coefficients, Constraint, Variable};
+use crate::simplex::{Coefficients, Constraint, ObjectiveSense, Operator, Variable};
 
 pub type Equation<R> = Row<R>;
 
@@ -25,12 +28,52 @@ pub struct Problem<R: RowValue, O: ObjectiveValue<R>> {
     pub objective_equation: Row<O>,
     pub rows: Vec<SimplexRow<R>>,
     pub point: Coefficients<R>,
+    pub decision_var_count: usize,
+    pub artificial_variables: Vec<Variable>,
+    pub sense: ObjectiveSense,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SolveStatus {
+    Optimal,
+    Unbounded,
+    Infeasible,
+    IterationLimit,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SolveResult<R: RowValue, O: ObjectiveValue<R>, P = Problem<R, O>> {
+    pub status: SolveStatus,
+    pub objective_value: O,
+    pub point: Coefficients<R>,
+    pub basis: Vec<Variable>,
+    pub iterations: usize,
+    pub infeasible_constraints: Vec<usize>,
+    /// The dual vector `y`: the change in the objective per unit increase of
+    /// each constraint's bound.
+    pub dual_values: Vec<R>,
+    /// The tableau the solver stopped at, for sensitivity analysis.
+    pub tableau: P,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SolveOptions {
+    pub iteration_limit: usize,
+}
+
+impl Default for SolveOptions {
+    fn default() -> Self {
+        Self {
+            iteration_limit: 10_000,
+        }
+    }
 }
 
 pub trait ProblemObserver<R: RowValue, O: ObjectiveValue<R>> {
     fn observe(&mut self, problem: Problem<R, O>);
 }
 
+#[derive(Default)]
 pub struct EmptyObserver;
 
 impl<R: RowValue, O: ObjectiveValue<R>> ProblemObserver<R, O> for EmptyObserver {
@@ -46,28 +89,147 @@ impl EmptyObserver {
 impl<R: RowValue, O: ObjectiveValue<R>> Problem<R, O> {
     pub fn new(
         objective_coeffs: &Coefficients<R>,
-        functional_constraints: &Vec<Constraint<R>>,
+        functional_constraints: &[Constraint<R>],
+    ) -> Self {
+        Self::with_sense(
+            ObjectiveSense::Maximize,
+            objective_coeffs,
+            functional_constraints,
+        )
+    }
+
+    /// The tableau always maximizes, so a minimization problem is built from
+    /// the negated objective and its objective value is negated back when
+    /// the result is reported.
+    pub fn with_sense(
+        sense: ObjectiveSense,
+        objective_coeffs: &Coefficients<R>,
+        functional_constraints: &[Constraint<R>],
     ) -> Self {
         Self {
             objective_equation: O::initial_objective_equation(
-                objective_coeffs,
+                &maximized_coefficients(sense, objective_coeffs),
                 functional_constraints,
             ),
             rows: initial_rows(functional_constraints, objective_coeffs.len()),
             point: initial_point(objective_coeffs, functional_constraints),
+            decision_var_count: objective_coeffs.len(),
+            artificial_variables: initial_artificial_variables(
+                functional_constraints,
+                objective_coeffs.len(),
+            ),
+            sense,
+        }
+    }
+
+    /// Appends a constraint to a solved problem without giving up its
+    /// basis. The coefficients cover the leading columns, usually the
+    /// decision variables, and the rest are zero. Each new row gets its own
+    /// slack column as its basic variable: a `>=` constraint is negated into
+    /// a `<=` row and an `=` constraint becomes one of each. A row the
+    /// current point violates starts out negative, for `dual::solve` to
+    /// repair.
+    pub fn add_constraint(&mut self, constraint: &Constraint<R>) {
+        if constraint.operator != Operator::GREATERTHANEQUAL {
+            let coefficients = padded_coefficients(self, constraint.coefficients.clone());
+            append_row(self, coefficients, constraint.bound.clone());
+        }
+        if constraint.operator != Operator::LESSTHANEQUAL {
+            let negated = constraint
+                .coefficients
+                .iter()
+                .cloned()
+                .map(|c| -c)
+                .collect();
+            let coefficients = padded_coefficients(self, negated);
+            append_row(self, coefficients, -constraint.bound.clone());
+        }
+    }
+}
+
+/// What a solver reads its result off: the basis and the objective row,
+/// whether the solver keeps every row of the tableau or computes them on
+/// demand.
+pub trait Tableau<R: RowValue, O: ObjectiveValue<R>> {
+    fn sense(&self) -> ObjectiveSense;
+    fn decision_var_count(&self) -> usize;
+    fn artificial_variables(&self) -> &[Variable];
+    /// Each row's basic variable with its value.
+    fn basic_values(&self) -> Vec<(Variable, R)>;
+    /// The constant of the objective row, in the maximizing sense of the
+    /// tableau.
+    fn objective_constant(&self) -> O;
+    /// The objective row entries of the slack or artificial column of each
+    /// constraint.
+    fn slack_objective_coefficients(&self) -> Vec<O>;
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> Tableau<R, O> for Problem<R, O> {
+    fn sense(&self) -> ObjectiveSense {
+        self.sense
+    }
+
+    fn decision_var_count(&self) -> usize {
+        self.decision_var_count
+    }
+
+    fn artificial_variables(&self) -> &[Variable] {
+        &self.artificial_variables
+    }
+
+    fn basic_values(&self) -> Vec<(Variable, R)> {
+        self.rows
+            .iter()
+            .map(|row| (row.basic_variable, row.equation.constraint.clone()))
+            .collect()
+    }
+
+    fn objective_constant(&self) -> O {
+        self.objective_equation.constraint.clone()
+    }
+
+    fn slack_objective_coefficients(&self) -> Vec<O> {
+        let first = self.decision_var_count;
+        self.objective_equation.coefficients[first..first + self.rows.len()].to_vec()
+    }
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>, P: Tableau<R, O>> SolveResult<R, O, P> {
+    /// Reads the result off the tableau a solver stopped at.
+    pub fn from_problem(problem: P, status: SolveStatus, iterations: usize) -> Self {
+        let objective_value = problem.objective_constant();
+        let basic_values = problem.basic_values();
+        let mut point = vec![R::zero(); problem.decision_var_count()];
+        for (var, value) in &basic_values {
+            if let Some(entry) = point.get_mut(*var) {
+                *entry = value.clone();
+            }
+        }
+        Self {
+            status,
+            objective_value: match problem.sense() {
+                ObjectiveSense::Maximize => objective_value,
+                ObjectiveSense::Minimize => -objective_value,
+            },
+            point,
+            basis: basic_values.into_iter().map(|(var, _)| var).collect(),
+            iterations,
+            infeasible_constraints: infeasible_constraints(&problem),
+            dual_values: dual_values(&problem),
+            tableau: problem,
         }
     }
 }
 
 fn initial_rows<R: RowValue>(
-    functional_constraints: &Vec<Constraint<R>>,
+    functional_constraints: &[Constraint<R>],
     nonbasic_var_count: usize,
 ) -> Vec<SimplexRow<R>> {
     let mut rows = vec![];
-    for (var, constraint) in functional_constraints.iter().enumerate() {
+    for var in 0..functional_constraints.len() {
         let row = SimplexRow {
             basic_variable: nonbasic_var_count + var,
-            equation: equality_constraint(constraint, var, functional_constraints.len()),
+            equation: equality_constraint(functional_constraints, var),
             ratio: R::zero(),
         };
         rows.push(row);
@@ -76,13 +238,17 @@ fn initial_rows<R: RowValue>(
 }
 
 fn equality_constraint<R: RowValue>(
-    constraint: &Constraint<R>,
+    functional_constraints: &[Constraint<R>],
     target_var: Variable,
-    basic_var_count: usize,
 ) -> Equation<R> {
-    let coeffs = &constraint.coefficients;
+    let constraint = &functional_constraints[target_var];
+    let coeffs = with_slack_variable(
+        &constraint.coefficients,
+        target_var,
+        functional_constraints.len(),
+    );
     Equation {
-        coefficients: with_slack_variable(coeffs, target_var, basic_var_count),
+        coefficients: with_surplus_variables(&coeffs, functional_constraints, target_var),
         constraint: constraint.bound.clone(),
     }
 }
@@ -103,17 +269,220 @@ fn with_slack_variable<R: RowValue>(
     coeffs
 }
 
+fn with_surplus_variables<R: RowValue>(
+    coefficients: &Coefficients<R>,
+    functional_constraints: &[Constraint<R>],
+    target_var: Variable,
+) -> Coefficients<R> {
+    let mut coeffs = coefficients.clone();
+    for (var, constraint) in functional_constraints.iter().enumerate() {
+        if has_surplus_variable(constraint) {
+            coeffs.push(if var == target_var {
+                -R::one()
+            } else {
+                R::zero()
+            });
+        }
+    }
+    coeffs
+}
+
+fn has_surplus_variable<R: RowValue>(constraint: &Constraint<R>) -> bool {
+    matches!(constraint.operator, Operator::GREATERTHANEQUAL)
+}
+
+fn surplus_var_count<R: RowValue>(functional_constraints: &[Constraint<R>]) -> usize {
+    functional_constraints
+        .iter()
+        .filter(|constraint| has_surplus_variable(constraint))
+        .count()
+}
+
+fn has_artificial_variable<R: RowValue>(constraint: &Constraint<R>) -> bool {
+    matches!(
+        constraint.operator,
+        Operator::EQUAL | Operator::GREATERTHANEQUAL
+    )
+}
+
+/// The objective coefficients of the maximization the tableau solves.
+fn maximized_coefficients<R: RowValue>(
+    sense: ObjectiveSense,
+    objective_coeffs: &Coefficients<R>,
+) -> Coefficients<R> {
+    match sense {
+        ObjectiveSense::Maximize => objective_coeffs.clone(),
+        ObjectiveSense::Minimize => objective_coeffs.iter().cloned().map(|c| -c).collect(),
+    }
+}
+
+/// The starting objective row `z - c·x = 0` over `column_count` columns,
+/// before any artificial variable is priced out.
+fn objective_row<R: RowValue, O: ObjectiveValue<R>>(
+    maximized_coeffs: &Coefficients<R>,
+    column_count: usize,
+) -> Row<O> {
+    let mut coefficients: Vec<O> = maximized_coeffs
+        .iter()
+        .map(|coeff| O::zero() + -coeff.clone())
+        .collect();
+    coefficients.resize(column_count, O::zero());
+    Row {
+        coefficients,
+        constraint: O::zero(),
+    }
+}
+
+/// Subtracts the penalty on `artificial_var` times its starting row from the
+/// objective row, so the artificial column starts priced out of the basis.
+fn price_out_artificial_variable<R: RowValue, O: ObjectiveValue<R>>(
+    objective: &mut Row<O>,
+    artificial_var: Variable,
+    coefficients: impl IntoIterator<Item = (Variable, R)>,
+    bound: R,
+) {
+    for (var, coeff) in coefficients {
+        if var != artificial_var {
+            objective.coefficients[var] =
+                objective.coefficients[var].clone() + -(O::artificial_penalty() * coeff);
+        }
+    }
+    objective.constraint = objective.constraint.clone() + -(O::artificial_penalty() * bound);
+}
+
+fn initial_artificial_variables<R: RowValue>(
+    functional_constraints: &[Constraint<R>],
+    nonbasic_var_count: usize,
+) -> Vec<Variable> {
+    functional_constraints
+        .iter()
+        .enumerate()
+        .filter(|(_, constraint)| has_artificial_variable(constraint))
+        .map(|(idx, _)| nonbasic_var_count + idx)
+        .collect()
+}
+
+/// Constraints whose artificial variable is still basic at a positive level,
+/// which means the point found does not satisfy them.
+fn infeasible_constraints<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &impl Tableau<R, O>,
+) -> Vec<usize> {
+    problem
+        .basic_values()
+        .into_iter()
+        .filter(|(var, _)| problem.artificial_variables().contains(var))
+        .filter(|(_, value)| value.is_positive())
+        .map(|(var, _)| var - problem.decision_var_count())
+        .collect()
+}
+
+/// Each constraint's slack or artificial column holds its dual value, since
+/// both columns start out as that constraint's unit column; an artificial
+/// column also holds the penalty on its variable. The tableau maximizes, so
+/// the values flip for minimization.
+fn dual_values<R: RowValue, O: ObjectiveValue<R>>(problem: &impl Tableau<R, O>) -> Vec<R> {
+    let first = problem.decision_var_count();
+    problem
+        .slack_objective_coefficients()
+        .into_iter()
+        .zip(first..)
+        .map(|(coeff, col)| {
+            let dual_value = if problem.artificial_variables().contains(&col) {
+                (coeff + -O::artificial_penalty()).finite_part()
+            } else {
+                coeff.finite_part()
+            };
+            match problem.sense() {
+                ObjectiveSense::Maximize => dual_value,
+                ObjectiveSense::Minimize => -dual_value,
+            }
+        })
+        .collect()
+}
+
 fn initial_point<R: RowValue>(
     objective_fn_coeffs: &Coefficients<R>,
-    constraints: &Vec<Constraint<R>>,
+    constraints: &[Constraint<R>],
 ) -> Coefficients<R> {
     let mut point = vec![R::zero(); objective_fn_coeffs.len()];
     for constraint in constraints {
         point.push(constraint.bound.clone());
     }
+    point.append(&mut vec![R::zero(); surplus_var_count(constraints)]);
     point
 }
 
+fn pivot<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &mut Problem<R, O>,
+    pivot_row_idx: usize,
+    variable: Variable,
+) {
+    set_basic_variable(problem, pivot_row_idx, variable);
+    normalize_equation(problem, pivot_row_idx, variable);
+    reduce_equations(problem, pivot_row_idx, variable);
+    set_new_point(problem);
+}
+
+fn set_basic_variable<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &mut Problem<R, O>,
+    var_idx: usize,
+    new_var: usize,
+) {
+    problem.rows[var_idx].basic_variable = new_var;
+}
+
+fn set_new_point<R: RowValue, O: ObjectiveValue<R>>(problem: &mut Problem<R, O>) {
+    problem.point.fill(R::zero());
+    for row in problem.rows.iter() {
+        problem.point[row.basic_variable] = row.equation.constraint.clone();
+    }
+}
+
+fn padded_coefficients<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    mut coefficients: Coefficients<R>,
+) -> Coefficients<R> {
+    coefficients.resize(problem.objective_equation.coefficients.len(), R::zero());
+    coefficients
+}
+
+/// Appends the row `coefficients · x <= bound`, over the problem's current
+/// columns, with a new slack column as its basic variable. The row is first
+/// rewritten in terms of the nonbasic columns. The slack column goes right
+/// after the existing ones, so column `d + i` stays the slack or artificial
+/// column of row `i`, and the surplus columns move up by one.
+fn append_row<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &mut Problem<R, O>,
+    coefficients: Coefficients<R>,
+    bound: R,
+) {
+    let mut equation = Equation {
+        coefficients,
+        constraint: bound,
+    };
+    for row in &problem.rows {
+        if equation.coefficients[row.basic_variable] != R::zero() {
+            reduce_row(&mut equation, &row.equation, row.basic_variable);
+        }
+    }
+    let slack = problem.decision_var_count + problem.rows.len();
+    for row in &mut problem.rows {
+        row.equation.coefficients.insert(slack, R::zero());
+        if row.basic_variable >= slack {
+            row.basic_variable += 1;
+        }
+    }
+    problem.objective_equation.coefficients.insert(slack, O::zero());
+    equation.coefficients.insert(slack, R::one());
+    problem.rows.push(SimplexRow {
+        basic_variable: slack,
+        equation,
+        ratio: R::zero(),
+    });
+    problem.point.insert(slack, R::zero());
+    set_new_point(problem);
+}
+
 fn normalize_equation<R: RowValue, O: ObjectiveValue<R>>(
     problem: &mut Problem<R, O>,
     equation_idx: usize,
@@ -121,12 +490,11 @@ fn normalize_equation<R: RowValue, O: ObjectiveValue<R>>(
 ) {
     let coeffs = &mut problem.rows[equation_idx].equation.coefficients;
     let coeff = coeffs[variable].clone();
-    let var_count = coeffs.len();
-    for var in 0..var_count {
+    for (var, c) in coeffs.iter_mut().enumerate() {
         if var == variable {
-            coeffs[var] = R::one();
+            *c = R::one();
         } else {
-            coeffs[var] = coeffs[var].clone() / coeff.clone();
+            *c = c.clone() / coeff.clone();
         }
     }
     let constraint = &mut problem.rows[equation_idx].equation.constraint;
@@ -164,4 +532,4 @@ where
         *coeff = coeff.clone() + -(factor.clone() * pivot_equation.coefficients[k].clone());
     }
     row.constraint = row.constraint.clone() + -(factor * pivot_equation.constraint.clone());
-}
\ No newline at end of file
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod test;
+
+use std::ops::{Add, Mul, Neg};
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::{Row, RowValue};
+use crate::simplex::tabular::{
+    self, primal, Problem, ProblemObserver, SimplexRow, SolveOptions, SolveResult, SolveStatus,
+};
+use crate::simplex::Variable;
+
+/// A problem whose columns may have upper bounds, handled without extra
+/// rows. A nonbasic column sits at either of its bounds; one at its upper
+/// bound is replaced by `upper - column`, so every nonbasic column of the
+/// tableau is still zero and the usual pivots apply unchanged.
+#[derive(PartialEq, Debug, Clone)]
+pub struct BoundedProblem<R: RowValue, O: ObjectiveValue<R>> {
+    pub problem: Problem<R, O>,
+    /// The upper bound of every column, `None` where there is none.
+    pub upper_bounds: Vec<Option<R>>,
+    /// Columns currently replaced by `upper - column`.
+    pub at_upper: Vec<bool>,
+}
+
+/// What stops the entering variable first.
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Blocking {
+    /// The basic variable of this row reaches zero or its upper bound.
+    Row(usize),
+    /// The entering variable reaches its own upper bound.
+    UpperBound,
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> BoundedProblem<R, O> {
+    /// `upper_bounds` covers the decision variables; slack, surplus and
+    /// artificial columns have none.
+    pub fn new(problem: Problem<R, O>, upper_bounds: &[Option<R>]) -> Self {
+        let column_count = problem.point.len();
+        let mut upper_bounds = upper_bounds.to_vec();
+        upper_bounds.resize(column_count, None);
+        Self {
+            problem,
+            upper_bounds,
+            at_upper: vec![false; column_count],
+        }
+    }
+}
+
+pub fn solve<R: RowValue, O: ObjectiveValue<R>>(
+    problem: BoundedProblem<R, O>,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    solve_with_options(problem, &SolveOptions::default(), observer)
+}
+
+/// Each iteration either pivots, as the primal simplex does, or moves the
+/// entering variable to its upper bound without changing the basis. A
+/// column with a negative upper bound makes the problem infeasible.
+pub fn solve_with_options<R: RowValue, O: ObjectiveValue<R>>(
+    mut problem: BoundedProblem<R, O>,
+    options: &SolveOptions,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    if problem
+        .upper_bounds
+        .iter()
+        .flatten()
+        .any(|u| *u < R::zero())
+    {
+        return result(problem, SolveStatus::Infeasible, 0);
+    }
+    let mut iterations = 0;
+    while !primal::is_optimal(&problem.problem) {
+        if iterations == options.iteration_limit {
+            return result(problem, SolveStatus::IterationLimit, iterations);
+        }
+        let Some(pivot_variable) = primal::pivot_variable(&problem.problem) else {
+            break;
+        };
+        set_ratios(&mut problem, pivot_variable);
+        let Some(blocking) = blocking(&problem, pivot_variable) else {
+            return result(problem, SolveStatus::Unbounded, iterations);
+        };
+        observer.observe(problem.problem.clone());
+        match blocking {
+            Blocking::UpperBound => flip(&mut problem, pivot_variable),
+            Blocking::Row(row_idx) => {
+                let row = &problem.problem.rows[row_idx];
+                let leaving = row.basic_variable;
+                let leaves_at_upper = row.equation.coefficients[pivot_variable] < R::zero();
+                tabular::pivot(&mut problem.problem, row_idx, pivot_variable);
+                if leaves_at_upper {
+                    flip(&mut problem, leaving);
+                }
+            }
+        }
+        iterations += 1;
+    }
+    observer.observe(problem.problem.clone());
+    let status = if tabular::infeasible_constraints(&problem.problem).is_empty() {
+        SolveStatus::Optimal
+    } else {
+        SolveStatus::Infeasible
+    };
+    result(problem, status, iterations)
+}
+
+/// The step each row allows the entering variable: up to the basic
+/// variable reaching zero when it decreases, or its upper bound when it
+/// increases.
+fn set_ratios<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &mut BoundedProblem<R, O>,
+    pivot_column: Variable,
+) {
+    for row in &mut problem.problem.rows {
+        let coeff = row.equation.coefficients[pivot_column].clone();
+        let value = row.equation.constraint.clone();
+        row.ratio = match &problem.upper_bounds[row.basic_variable] {
+            Some(upper) if coeff < R::zero() => (upper.clone() + -value) / -coeff,
+            _ => value / coeff,
+        };
+    }
+}
+
+/// The smallest ratio among the rows, or the entering variable's own upper
+/// bound if that is smaller. Ties go to the row, so the basis changes.
+fn blocking<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &BoundedProblem<R, O>,
+    pivot_column: Variable,
+) -> Option<Blocking> {
+    let row = eligible_rows(problem, pivot_column)
+        .min_by(|(_, r1), (_, r2)| r1.ratio.cmp(&r2.ratio))
+        .map(|(idx, row)| (Blocking::Row(idx), row.ratio.clone()));
+    let own = problem.upper_bounds[pivot_column]
+        .clone()
+        .map(|upper| (Blocking::UpperBound, upper));
+    match (row, own) {
+        (Some((_, ratio)), Some((own, upper))) if upper < ratio => Some(own),
+        (row, own) => row.or(own).map(|(blocking, _)| blocking),
+    }
+}
+
+/// Rows whose basic variable limits the step: those that decrease as the
+/// pivot column enters, and those with an upper bound that increase.
+fn eligible_rows<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &BoundedProblem<R, O>,
+    pivot_column: Variable,
+) -> impl Iterator<Item = (usize, &SimplexRow<R>)> {
+    problem
+        .problem
+        .rows
+        .iter()
+        .enumerate()
+        .filter(move |(_, row)| {
+            let coeff = &row.equation.coefficients[pivot_column];
+            if coeff.is_positive() {
+                !row.ratio.is_negative()
+            } else {
+                coeff.is_negative() && problem.upper_bounds[row.basic_variable].is_some()
+            }
+        })
+}
+
+/// Moves a nonbasic column to its other bound by substituting
+/// `upper - column` for it.
+fn flip<R: RowValue, O: ObjectiveValue<R>>(problem: &mut BoundedProblem<R, O>, column: Variable) {
+    let upper = problem.upper_bounds[column]
+        .clone()
+        .expect("only columns with an upper bound move to it");
+    for row in &mut problem.problem.rows {
+        flip_column(&mut row.equation, column, &upper);
+    }
+    flip_column(&mut problem.problem.objective_equation, column, &upper);
+    problem.at_upper[column] = !problem.at_upper[column];
+    tabular::set_new_point(&mut problem.problem);
+}
+
+fn flip_column<T, R: RowValue>(row: &mut Row<T>, column: Variable, upper: &R)
+where
+    T: Clone + Add<Output = T> + Neg<Output = T> + Mul<R, Output = T>,
+{
+    let coeff = row.coefficients[column].clone();
+    row.constraint = row.constraint.clone() + -(coeff.clone() * upper.clone());
+    row.coefficients[column] = -coeff;
+}
+
+/// The result's point is in the original columns; its tableau keeps the
+/// substituted ones.
+fn result<R: RowValue, O: ObjectiveValue<R>>(
+    problem: BoundedProblem<R, O>,
+    status: SolveStatus,
+    iterations: usize,
+) -> SolveResult<R, O> {
+    let BoundedProblem {
+        problem,
+        upper_bounds,
+        at_upper,
+    } = problem;
+    let mut result = SolveResult::from_problem(problem, status, iterations);
+    for (var, value) in result.point.iter_mut().enumerate() {
+        if let (true, Some(upper)) = (at_upper[var], &upper_bounds[var]) {
+            *value = upper.clone() + -value.clone();
+        }
+    }
+    result
+}
@@ -0,0 +1,154 @@
+use crate::simplex::tabular::primal::bounded::{self as sut, BoundedProblem};
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::{EmptyObserver, SolveOptions, SolveStatus};
+use crate::simplex::test::{frac, lower_bound_constraint, upper_bound_constraint};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, ObjectiveSense};
+
+fn bounded(
+    objective_coeffs: Vec<Value>,
+    constraints: &[Constraint<Value>],
+    upper_bounds: &[Option<Value>],
+) -> BoundedProblem<Value, MObjectiveValue> {
+    BoundedProblem::new(
+        PrimalProblem::new(&objective_coeffs, constraints),
+        upper_bounds,
+    )
+}
+
+#[test]
+fn pads_upper_bounds_for_slack_columns() {
+    let problem = bounded(
+        vec![frac(1, 1), frac(1, 1)],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), frac(1, 1)],
+            frac(4, 1),
+        )],
+        &[Some(frac(3, 1))],
+    );
+    assert_eq!(vec![Some(frac(3, 1)), None, None], problem.upper_bounds);
+    assert_eq!(vec![false; 3], problem.at_upper);
+}
+
+#[test]
+fn moves_entering_variable_to_its_upper_bound() {
+    let problem = bounded(
+        vec![frac(2, 1), frac(1, 1)],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), frac(1, 1)],
+            frac(4, 1),
+        )],
+        &[Some(frac(3, 1)), Some(frac(2, 1))],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(7, 1)), result.objective_value);
+    assert_eq!(2, result.iterations);
+    assert_eq!(1, result.tableau.rows.len());
+}
+
+#[test]
+fn moves_leaving_variable_to_its_upper_bound() {
+    let problem = bounded(
+        vec![frac(2, 1), frac(1, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1), -frac(1, 1)], frac(1, 1)),
+            upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(6, 1)),
+        ],
+        &[Some(frac(3, 1)), None],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(3, 1), frac(3, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(9, 1)), result.objective_value);
+    assert_eq!(3, result.iterations);
+}
+
+#[test]
+fn matches_solve_with_bound_rows() {
+    let constraints = [
+        upper_bound_constraint(vec![frac(1, 1), frac(2, 1), frac(1, 1)], frac(10, 1)),
+        lower_bound_constraint(vec![frac(1, 1), frac(1, 1), value::zero()], frac(2, 1)),
+    ];
+    let objective_coeffs = vec![frac(3, 1), frac(2, 1), frac(4, 1)];
+    let upper_bounds = [Some(frac(4, 1)), Some(frac(5, 2)), Some(frac(1, 1))];
+    let mut with_rows = constraints.to_vec();
+    for (var, upper) in upper_bounds.iter().enumerate() {
+        let mut coefficients = vec![value::zero(); 3];
+        coefficients[var] = frac(1, 1);
+        with_rows.push(upper_bound_constraint(coefficients, upper.unwrap()));
+    }
+    let expected = primal::solve(
+        PrimalProblem::new(&objective_coeffs, &with_rows),
+        &mut EmptyObserver {},
+    );
+    let result = sut::solve(
+        bounded(objective_coeffs, &constraints, &upper_bounds),
+        &mut EmptyObserver {},
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(expected.point, result.point);
+    assert_eq!(expected.objective_value, result.objective_value);
+}
+
+#[test]
+fn solves_minimization_with_artificial_rows() {
+    let problem = BoundedProblem::new(
+        PrimalProblem::with_sense(
+            ObjectiveSense::Minimize,
+            &vec![frac(1, 1), frac(3, 1)],
+            &[lower_bound_constraint(
+                vec![frac(1, 1), frac(1, 1)],
+                frac(2, 1),
+            )],
+        ),
+        &[Some(frac(1, 1)), None],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(1, 1), frac(1, 1)], result.point);
+    assert_eq!(MObjectiveValue::from(frac(4, 1)), result.objective_value);
+}
+
+#[test]
+fn reports_unbounded_and_infeasible_problems() {
+    let problem = bounded(
+        vec![frac(1, 1), frac(1, 1)],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), -frac(1, 1)],
+            frac(1, 1),
+        )],
+        &[Some(frac(3, 1)), None],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Unbounded, result.status);
+    let problem = bounded(
+        vec![frac(1, 1)],
+        &[lower_bound_constraint(vec![frac(1, 1)], frac(2, 1))],
+        &[Some(frac(1, 1))],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![0], result.infeasible_constraints);
+    let problem = bounded(vec![frac(1, 1)], &[], &[Some(-frac(1, 1))]);
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Infeasible, result.status);
+}
+
+#[test]
+fn stops_at_iteration_limit() {
+    let problem = bounded(
+        vec![frac(2, 1), frac(1, 1)],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), frac(1, 1)],
+            frac(4, 1),
+        )],
+        &[Some(frac(3, 1)), Some(frac(2, 1))],
+    );
+    let options = SolveOptions { iteration_limit: 1 };
+    let result = sut::solve_with_options(problem, &options, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(vec![frac(3, 1), value::zero()], result.point);
+}
@@ -6,50 +6,44 @@ use std::{
     ops::{Add, Div, Mul, Neg},
 };
 
-use fraction::{Signed, Zero};
-
 use crate::simplex::{
-    objectivevalue::ObjectiveValue, rowvalue::Row, value, value::Value, Coefficients, Constraint,
-    Operator,
+    objectivevalue::ObjectiveValue,
+    rowvalue::{Row, RowValue},
+    tabular,
+    value::Value,
+    Coefficients, Constraint,
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct MObjectiveValue {
-    finite: Value,
-    m: Value,
+pub struct MObjectiveValue<R: RowValue = Value> {
+    finite: R,
+    m: R,
 }
 
-impl MObjectiveValue {
-    pub fn from(finite: Value) -> MObjectiveValue {
+impl<R: RowValue> MObjectiveValue<R> {
+    pub fn from(finite: R) -> MObjectiveValue<R> {
         MObjectiveValue {
-            finite: finite,
-            m: value::zero(),
+            finite,
+            m: R::zero(),
         }
     }
 
-    pub fn from_m(finite: Value, m: Value) -> MObjectiveValue {
-        MObjectiveValue {
-            finite: finite,
-            m: m,
-        }
+    pub fn from_m(finite: R, m: R) -> MObjectiveValue<R> {
+        MObjectiveValue { finite, m }
     }
 
-    pub fn zero() -> MObjectiveValue {
-        MObjectiveValue::from(value::zero())
+    pub fn zero() -> MObjectiveValue<R> {
+        MObjectiveValue::from(R::zero())
     }
 }
 
-impl PartialOrd for MObjectiveValue {
+impl<R: RowValue> PartialOrd for MObjectiveValue<R> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.m.partial_cmp(&other.m) {
-            Some(core::cmp::Ordering::Equal) => {}
-            ord => return ord,
-        }
-        self.finite.partial_cmp(&other.finite)
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for MObjectiveValue {
+impl<R: RowValue> Ord for MObjectiveValue<R> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.m.cmp(&other.m) {
             core::cmp::Ordering::Equal => {}
@@ -59,10 +53,10 @@ impl Ord for MObjectiveValue {
     }
 }
 
-impl Add for MObjectiveValue {
-    type Output = MObjectiveValue;
+impl<R: RowValue> Add for MObjectiveValue<R> {
+    type Output = MObjectiveValue<R>;
 
-    fn add(self, rhs: MObjectiveValue) -> Self::Output {
+    fn add(self, rhs: MObjectiveValue<R>) -> Self::Output {
         MObjectiveValue {
             finite: self.finite + rhs.finite,
             m: self.m + rhs.m,
@@ -70,8 +64,19 @@ impl Add for MObjectiveValue {
     }
 }
 
-impl Neg for MObjectiveValue {
-    type Output = MObjectiveValue;
+impl<R: RowValue> Add<R> for MObjectiveValue<R> {
+    type Output = MObjectiveValue<R>;
+
+    fn add(self, rhs: R) -> Self::Output {
+        MObjectiveValue {
+            finite: self.finite + rhs,
+            m: self.m,
+        }
+    }
+}
+
+impl<R: RowValue> Neg for MObjectiveValue<R> {
+    type Output = MObjectiveValue<R>;
 
     fn neg(self) -> Self::Output {
         MObjectiveValue {
@@ -81,102 +86,92 @@ impl Neg for MObjectiveValue {
     }
 }
 
-impl Mul<Value> for MObjectiveValue {
-    type Output = MObjectiveValue;
+impl<R: RowValue> Mul<R> for MObjectiveValue<R> {
+    type Output = MObjectiveValue<R>;
 
-    fn mul(self, rhs: Value) -> Self::Output {
+    fn mul(self, rhs: R) -> Self::Output {
         MObjectiveValue {
-            finite: self.finite * rhs,
+            finite: self.finite * rhs.clone(),
             m: self.m * rhs,
         }
     }
 }
 
-impl Div<Value> for MObjectiveValue {
-    type Output = MObjectiveValue;
+impl<R: RowValue> Div<R> for MObjectiveValue<R> {
+    type Output = MObjectiveValue<R>;
 
-    fn div(self, rhs: Value) -> Self::Output {
+    fn div(self, rhs: R) -> Self::Output {
         MObjectiveValue {
-            finite: self.finite / rhs,
+            finite: self.finite / rhs.clone(),
             m: self.m / rhs,
         }
     }
 }
 
-impl Display for MObjectiveValue {
+impl<R: RowValue> Display for MObjectiveValue<R> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.m.is_zero() {
-            self.finite.fmt(f)
-        } else if self.finite.is_zero() {
-            let _ = self.m.fmt(f);
+        if self.m == R::zero() {
+            Display::fmt(&self.finite, f)
+        } else if self.finite == R::zero() {
+            let _ = Display::fmt(&self.m, f);
             write!(f, "M")
         } else {
-            let _ = self.finite.fmt(f);
-            if self.m.is_negative() {
+            let _ = Display::fmt(&self.finite, f);
+            let m = if self.m < R::zero() {
                 let _ = write!(f, " - ");
+                -self.m.clone()
             } else {
                 let _ = write!(f, " + ");
-            }
-            let _ = self.m.abs().fmt(f);
+                self.m.clone()
+            };
+            let _ = Display::fmt(&m, f);
             write!(f, "M")
         }
     }
 }
 
-fn find_equality_constraint(
-    functional_constraints: &Vec<Constraint<Value>>,
-) -> Option<Constraint<Value>> {
-    for constraint in functional_constraints {
-        match constraint.operator {
-            Operator::EQUAL => return Some(constraint.clone()),
-            _ => {}
-        }
+impl<R: RowValue> ObjectiveValue<R> for MObjectiveValue<R> {
+    fn zero() -> Self {
+        MObjectiveValue::from(R::zero())
     }
-    return None;
-}
 
-fn initial_objective_coeffs(
-    coeffs: &Coefficients<Value>,
-    equality_constraint_opt: &Option<Constraint<Value>>,
-) -> Vec<MObjectiveValue> {
-    let mut obj_coeffs = vec![MObjectiveValue::zero(); coeffs.len()];
-    for i in 0..coeffs.len() {
-        obj_coeffs[i] = -MObjectiveValue::from(coeffs[i].clone());
-    }
-    match equality_constraint_opt {
-        Some(equality_constraint) => {
-            for i in 0..equality_constraint.coefficients.len() {
-                obj_coeffs[i] = obj_coeffs[i].clone()
-                    + -MObjectiveValue::from_m(value::zero(), equality_constraint.coefficients[i])
-            }
+    /// Non-negative, ordering by the M part first, with the sign checks of
+    /// `R` so floating point values near zero count as zero.
+    fn is_optimal(&self) -> bool {
+        if self.m.is_positive() || self.m.is_negative() {
+            self.m.is_positive()
+        } else {
+            !self.finite.is_improving()
         }
-        None => {}
     }
-    obj_coeffs
-}
 
-impl ObjectiveValue<Value> for MObjectiveValue {
-    fn is_optimal(&self) -> bool {
-        *self >= MObjectiveValue::zero()
+    fn finite_part(&self) -> R {
+        self.finite.clone()
+    }
+
+    fn artificial_penalty() -> Self {
+        MObjectiveValue::from_m(R::zero(), R::one())
     }
 
     fn initial_objective_equation(
-        objective_fn_coeffs: &Coefficients<Value>,
-        functional_constraints: &Vec<Constraint<Value>>,
+        objective_fn_coeffs: &Coefficients<R>,
+        functional_constraints: &[Constraint<R>],
     ) -> Row<Self> {
-        let nonbasic_var_count = functional_constraints.len();
-        let equality_constraint_opt = find_equality_constraint(functional_constraints);
-        let mut coefficients =
-            initial_objective_coeffs(objective_fn_coeffs, &equality_constraint_opt);
-        coefficients.append(&mut vec![MObjectiveValue::zero(); nonbasic_var_count]);
-        Row {
-            coefficients: coefficients,
-            constraint: match equality_constraint_opt {
-                Some(equality_constraint) => {
-                    -MObjectiveValue::from_m(value::zero(), equality_constraint.bound)
-                }
-                None => MObjectiveValue::zero(),
-            },
+        let column_count = objective_fn_coeffs.len()
+            + functional_constraints.len()
+            + tabular::surplus_var_count(functional_constraints);
+        let mut objective = tabular::objective_row(objective_fn_coeffs, column_count);
+        for (idx, constraint) in functional_constraints.iter().enumerate() {
+            if tabular::has_artificial_variable(constraint) {
+                let equation = tabular::equality_constraint(functional_constraints, idx);
+                tabular::price_out_artificial_variable(
+                    &mut objective,
+                    objective_fn_coeffs.len() + idx,
+                    equation.coefficients.into_iter().enumerate(),
+                    equation.constraint,
+                );
+            }
         }
+        objective
     }
 }
@@ -1,8 +1,15 @@
 use fraction::{ConstZero, Fraction};
 
 use crate::simplex::{
-    tabular::primal::mobjectivevalue::{self as sut},
+    float::{DefaultTolerances, Float},
+    objectivevalue::ObjectiveValue,
+    tabular::primal::{
+        self,
+        mobjectivevalue::{self as sut},
+    },
+    tabular::{EmptyObserver, Problem, SolveStatus},
     value::Value,
+    Constraint, ObjectiveSense, Operator,
 };
 
 // reconcile with other frac fn.
@@ -206,3 +213,40 @@ fn mvalue_div_finite_and_m() {
     let prod = sut::MObjectiveValue::from_m(frac(6, 10), frac(18, 20));
     assert_eq!(prod, f1 / f2);
 }
+
+#[test]
+fn mvalue_finite_part_drops_m() {
+    let f = sut::MObjectiveValue::from_m(frac(1, 2), frac(3, 4));
+    assert_eq!(frac(1, 2), f.finite_part());
+}
+
+#[test]
+fn mvalue_solves_big_m_problem_over_floats() {
+    let float = |value: f64| Float::new(value);
+    let constraint = |operator, coefficients: [f64; 2], bound| Constraint {
+        operator,
+        coefficients: coefficients.iter().map(|&c| float(c)).collect(),
+        bound: float(bound),
+    };
+    let problem = Problem::<Float, sut::MObjectiveValue<Float>>::with_sense(
+        ObjectiveSense::Minimize,
+        &vec![float(0.2), float(0.3)],
+        &[
+            constraint(Operator::GREATERTHANEQUAL, [1.0, 1.0], 0.4),
+            constraint(Operator::EQUAL, [1.0, -1.0], 0.0),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(
+        sut::MObjectiveValue::from(float(0.1)),
+        result.objective_value
+    );
+    assert_eq!(vec![float(0.2), float(0.2)], result.point);
+}
+
+#[test]
+fn mvalue_formats_over_floats() {
+    let f = sut::MObjectiveValue::from_m(Float::new(1.5), Float::<DefaultTolerances>::new(-2.0));
+    assert_eq!("1.5 - 2M", format!("{}", f));
+}
@@ -1,58 +1,92 @@
 #[cfg(test)]
 pub mod test;
 
+pub mod bounded;
 pub mod mobjectivevalue;
+pub mod pivotrule;
+pub mod strategy;
+pub mod twophase;
 
-use super::{Problem, ProblemObserver};
+use super::{Problem, ProblemObserver, SimplexRow, SolveOptions, SolveResult, SolveStatus};
 use crate::simplex::objectivevalue::ObjectiveValue;
 use crate::simplex::rowvalue::{Row, RowValue};
 use crate::simplex::tabular;
 use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::pivotrule::{Dantzig, PivotRule};
 use crate::simplex::value::Value;
-use crate::simplex::{Coefficients, Variable};
+use crate::simplex::Variable;
 
 pub type PrimalProblem = Problem<Value, MObjectiveValue>;
 pub type MObjectiveEquation = Row<MObjectiveValue>;
 
 pub fn solve<R: RowValue, O: ObjectiveValue<R>>(
+    problem: Problem<R, O>,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    solve_with_options(problem, &SolveOptions::default(), observer)
+}
+
+pub fn solve_with_options<R: RowValue, O: ObjectiveValue<R>>(
+    problem: Problem<R, O>,
+    options: &SolveOptions,
+    observer: &mut impl ProblemObserver<R, O>,
+) -> SolveResult<R, O> {
+    solve_with_rule(problem, &Dantzig, options, observer)
+}
+
+pub fn solve_with_rule<R: RowValue, O: ObjectiveValue<R>>(
     mut problem: Problem<R, O>,
+    rule: &impl PivotRule<R, O>,
+    options: &SolveOptions,
     observer: &mut impl ProblemObserver<R, O>,
-) -> Coefficients<R> {
+) -> SolveResult<R, O> {
+    let mut iterations = 0;
     while !is_optimal(&problem) {
-        let Some(pivot_variable) = pivot_variable(&problem) else {
-            return problem.point;
+        if iterations == options.iteration_limit {
+            return SolveResult::from_problem(problem, SolveStatus::IterationLimit, iterations);
+        }
+        let Some(pivot_variable) = rule.pivot_variable(&problem) else {
+            break;
         };
         set_ratios(&mut problem, pivot_variable);
-        let Some(pivot_row_idx) = pivot_row_idx(&problem) else {
-            return problem.point;
+        let Some(pivot_row_idx) = rule.pivot_row_idx(&problem, pivot_variable) else {
+            return SolveResult::from_problem(problem, SolveStatus::Unbounded, iterations);
         };
         observer.observe(problem.clone());
-        set_basic_variable(&mut problem, pivot_row_idx, pivot_variable);
-        tabular::normalize_equation(&mut problem, pivot_row_idx, pivot_variable);
-        tabular::reduce_equations(&mut problem, pivot_row_idx, pivot_variable);
-        set_new_point(&mut problem);
+        tabular::pivot(&mut problem, pivot_row_idx, pivot_variable);
+        iterations += 1;
     }
     observer.observe(problem.clone());
-    return problem.point;
+    let status = if tabular::infeasible_constraints(&problem).is_empty() {
+        SolveStatus::Optimal
+    } else {
+        SolveStatus::Infeasible
+    };
+    SolveResult::from_problem(problem, status, iterations)
 }
 
 fn is_optimal<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> bool {
-    problem
-        .objective_equation
-        .coefficients
-        .iter()
-        .all(|v| v.is_optimal())
+    entering_candidates(problem).all(|(_, v)| v.is_optimal())
 }
 
 fn pivot_variable<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> Option<Variable> {
+    entering_candidates(problem)
+        .min_by(|(_, v1), (_, v2)| v1.cmp(v2))
+        .unzip()
+        .0
+}
+
+/// Objective row entries of the columns that may enter the basis. An
+/// artificial variable never re-enters once it has left.
+fn entering_candidates<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+) -> impl Iterator<Item = (Variable, &O)> {
     problem
         .objective_equation
         .coefficients
         .iter()
         .enumerate()
-        .min_by(|(_, v1), (_, v2)| v1.cmp(v2))
-        .unzip()
-        .0
+        .filter(|(var, _)| !problem.artificial_variables.contains(var))
 }
 
 fn set_ratios<R: RowValue, O: ObjectiveValue<R>>(
@@ -65,28 +99,24 @@ fn set_ratios<R: RowValue, O: ObjectiveValue<R>>(
     }
 }
 
-fn pivot_row_idx<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> Option<usize> {
-    problem
-        .rows
-        .iter()
-        .enumerate()
-        .filter(|(_, row)| row.ratio > R::zero() && row.ratio.is_finite())
+fn pivot_row_idx<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    pivot_column: Variable,
+) -> Option<usize> {
+    eligible_rows(problem, pivot_column)
         .min_by(|(_, r1), (_, r2)| r1.ratio.cmp(&r2.ratio))
         .unzip()
         .0
 }
 
-fn set_basic_variable<R: RowValue, O: ObjectiveValue<R>>(
-    problem: &mut Problem<R, O>,
-    var_idx: usize,
-    new_var: usize,
-) {
-    problem.rows[var_idx].basic_variable = new_var;
-}
-
-fn set_new_point<R: RowValue, O: ObjectiveValue<R>>(problem: &mut Problem<R, O>) {
-    problem.point.fill(R::zero());
-    for row in problem.rows.iter() {
-        problem.point[row.basic_variable] = row.equation.constraint.clone();
-    }
+/// Rows that can leave the basis when `pivot_column` enters: those with a
+/// positive coefficient in the pivot column. A zero ratio is a legitimate
+/// degenerate pivot.
+fn eligible_rows<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    pivot_column: Variable,
+) -> impl Iterator<Item = (usize, &SimplexRow<R>)> {
+    problem.rows.iter().enumerate().filter(move |(_, row)| {
+        row.equation.coefficients[pivot_column].is_positive() && !row.ratio.is_negative()
+    })
 }
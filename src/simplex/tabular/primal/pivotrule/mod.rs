@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod test;
+
+use std::cmp::Ordering;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::primal;
+use crate::simplex::tabular::{Problem, SimplexRow};
+use crate::simplex::Variable;
+
+/// Chooses the entering variable and leaving row of each primal simplex
+/// iteration. `pivot_row_idx` is called after the ratios for the entering
+/// column have been set.
+pub trait PivotRule<R: RowValue, O: ObjectiveValue<R>> {
+    fn pivot_variable(&self, problem: &Problem<R, O>) -> Option<Variable>;
+    fn pivot_row_idx(&self, problem: &Problem<R, O>, pivot_column: Variable) -> Option<usize>;
+}
+
+/// Most negative reduced cost enters, smallest ratio leaves. Fast in
+/// practice but can cycle on degenerate problems.
+pub struct Dantzig;
+
+/// Lowest-indexed improving variable enters and ties in the ratio test go to
+/// the lowest-indexed basic variable. Never cycles.
+pub struct Bland;
+
+/// Most negative reduced cost enters and ties in the ratio test are broken
+/// by comparing the rows of the initial basis columns lexicographically.
+/// Never cycles.
+pub struct Lexicographic;
+
+impl<R: RowValue, O: ObjectiveValue<R>> PivotRule<R, O> for Dantzig {
+    fn pivot_variable(&self, problem: &Problem<R, O>) -> Option<Variable> {
+        primal::pivot_variable(problem)
+    }
+
+    fn pivot_row_idx(&self, problem: &Problem<R, O>, pivot_column: Variable) -> Option<usize> {
+        primal::pivot_row_idx(problem, pivot_column)
+    }
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> PivotRule<R, O> for Bland {
+    fn pivot_variable(&self, problem: &Problem<R, O>) -> Option<Variable> {
+        primal::entering_candidates(problem)
+            .find(|(_, v)| !v.is_optimal())
+            .unzip()
+            .0
+    }
+
+    fn pivot_row_idx(&self, problem: &Problem<R, O>, pivot_column: Variable) -> Option<usize> {
+        primal::eligible_rows(problem, pivot_column)
+            .min_by(|(_, r1), (_, r2)| {
+                r1.ratio
+                    .cmp(&r2.ratio)
+                    .then(r1.basic_variable.cmp(&r2.basic_variable))
+            })
+            .unzip()
+            .0
+    }
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> PivotRule<R, O> for Lexicographic {
+    fn pivot_variable(&self, problem: &Problem<R, O>) -> Option<Variable> {
+        primal::pivot_variable(problem)
+    }
+
+    fn pivot_row_idx(&self, problem: &Problem<R, O>, pivot_column: Variable) -> Option<usize> {
+        let initial_basis =
+            problem.decision_var_count..problem.decision_var_count + problem.rows.len();
+        primal::eligible_rows(problem, pivot_column)
+            .min_by(|(_, r1), (_, r2)| {
+                r1.ratio.cmp(&r2.ratio).then_with(|| {
+                    initial_basis
+                        .clone()
+                        .map(|var| scaled_cmp(r1, r2, var, pivot_column))
+                        .find(|ord| *ord != Ordering::Equal)
+                        .unwrap_or(Ordering::Equal)
+                })
+            })
+            .unzip()
+            .0
+    }
+}
+
+fn scaled_cmp<R: RowValue>(
+    r1: &SimplexRow<R>,
+    r2: &SimplexRow<R>,
+    var: Variable,
+    pivot_column: Variable,
+) -> Ordering {
+    let scaled = |row: &SimplexRow<R>| {
+        row.equation.coefficients[var].clone() / row.equation.coefficients[pivot_column].clone()
+    };
+    scaled(r1).cmp(&scaled(r2))
+}
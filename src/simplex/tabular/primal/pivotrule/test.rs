@@ -0,0 +1,135 @@
+use crate::simplex::tabular::primal::mobjectivevalue::test::mvalue_from;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::pivotrule::{self as sut, PivotRule};
+use crate::simplex::tabular::primal::{self, MObjectiveEquation, PrimalProblem};
+use crate::simplex::tabular::{
+    EmptyObserver, Equation, SimplexRow, SolveOptions, SolveResult, SolveStatus,
+};
+use crate::simplex::test::{frac, tableau, upper_bound_constraint};
+use crate::simplex::value;
+use crate::simplex::value::Value;
+
+// Beale's example, which cycles under the textbook Dantzig rule.
+fn make_beale_problem() -> PrimalProblem {
+    let objective_function = vec![frac(3, 4), -frac(20, 1), frac(1, 2), -frac(6, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(
+            vec![frac(1, 4), -frac(8, 1), -frac(1, 1), frac(9, 1)],
+            value::zero(),
+        ),
+        upper_bound_constraint(
+            vec![frac(1, 2), -frac(12, 1), -frac(1, 2), frac(3, 1)],
+            value::zero(),
+        ),
+        upper_bound_constraint(
+            vec![value::zero(), value::zero(), frac(1, 1), value::zero()],
+            frac(1, 1),
+        ),
+    ];
+    PrimalProblem::new(&objective_function, &fn_constraints)
+}
+
+fn make_tied_problem() -> PrimalProblem {
+    let mut problem = tableau(
+        MObjectiveEquation {
+            coefficients: vec![
+                mvalue_from(0, 1),
+                -mvalue_from(1, 1),
+                -mvalue_from(3, 1),
+                mvalue_from(0, 1),
+                mvalue_from(0, 1),
+            ],
+            constraint: mvalue_from(0, 1),
+        },
+        vec![
+            SimplexRow {
+                basic_variable: 4,
+                equation: Equation {
+                    coefficients: vec![frac(1, 1), frac(2, 1), frac(1, 1), frac(0, 1), frac(1, 1)],
+                    constraint: frac(4, 1),
+                },
+                ratio: frac(2, 1),
+            },
+            SimplexRow {
+                basic_variable: 3,
+                equation: Equation {
+                    coefficients: vec![frac(0, 1), frac(1, 1), frac(1, 1), frac(1, 1), frac(0, 1)],
+                    constraint: frac(2, 1),
+                },
+                ratio: frac(2, 1),
+            },
+        ],
+        vec![],
+    );
+    problem.decision_var_count = 3;
+    problem
+}
+
+fn solve_with(
+    rule: &impl PivotRule<Value, MObjectiveValue>,
+) -> SolveResult<Value, MObjectiveValue> {
+    let options = SolveOptions {
+        iteration_limit: 50,
+    };
+    primal::solve_with_rule(
+        make_beale_problem(),
+        rule,
+        &options,
+        &mut EmptyObserver::new(),
+    )
+}
+
+#[test]
+fn dantzig_cycles_on_beale_problem() {
+    let result = solve_with(&sut::Dantzig);
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+}
+
+#[test]
+fn bland_solves_beale_problem() {
+    let result = solve_with(&sut::Bland);
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(5, 4)), result.objective_value);
+    assert_eq!(
+        vec![frac(1, 1), value::zero(), frac(1, 1), value::zero()],
+        result.point
+    );
+}
+
+#[test]
+fn lexicographic_solves_beale_problem() {
+    let result = solve_with(&sut::Lexicographic);
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(5, 4)), result.objective_value);
+    assert_eq!(
+        vec![frac(1, 1), value::zero(), frac(1, 1), value::zero()],
+        result.point
+    );
+}
+
+#[test]
+fn dantzig_picks_most_negative_variable() {
+    assert_eq!(Some(2), sut::Dantzig.pivot_variable(&make_tied_problem()));
+}
+
+#[test]
+fn bland_picks_lowest_improving_variable() {
+    assert_eq!(Some(1), sut::Bland.pivot_variable(&make_tied_problem()));
+}
+
+#[test]
+fn dantzig_breaks_ratio_ties_by_row_order() {
+    assert_eq!(Some(0), sut::Dantzig.pivot_row_idx(&make_tied_problem(), 2));
+}
+
+#[test]
+fn bland_breaks_ratio_ties_by_lowest_basic_variable() {
+    assert_eq!(Some(1), sut::Bland.pivot_row_idx(&make_tied_problem(), 2));
+}
+
+#[test]
+fn lexicographic_breaks_ratio_ties_by_initial_basis_columns() {
+    let mut problem = make_tied_problem();
+    problem.rows.reverse();
+    assert_eq!(Some(1), sut::Lexicographic.pivot_row_idx(&problem, 2));
+}
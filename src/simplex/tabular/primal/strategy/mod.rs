@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::twophase::{self, PlainObjectiveValue};
+use crate::simplex::tabular::{primal, Problem, ProblemObserver, SolveOptions, SolveResult};
+
+/// How the primal simplex gets rid of the artificial variables that start
+/// out basic in `>=` and `=` rows. Each strategy has its own kind of
+/// objective value.
+pub trait Strategy<R: RowValue> {
+    type Objective: ObjectiveValue<R>;
+
+    fn solve(
+        &self,
+        problem: Problem<R, Self::Objective>,
+        options: &SolveOptions,
+        observer: &mut impl ProblemObserver<R, Self::Objective>,
+    ) -> SolveResult<R, Self::Objective>;
+}
+
+/// Penalizes artificial variables with a symbolic big M in a single phase.
+pub struct BigM;
+
+/// Drives artificial variables to zero in phase I, then optimizes the real
+/// objective in phase II.
+pub struct TwoPhase;
+
+impl<R: RowValue> Strategy<R> for BigM {
+    type Objective = MObjectiveValue<R>;
+
+    fn solve(
+        &self,
+        problem: Problem<R, Self::Objective>,
+        options: &SolveOptions,
+        observer: &mut impl ProblemObserver<R, Self::Objective>,
+    ) -> SolveResult<R, Self::Objective> {
+        primal::solve_with_options(problem, options, observer)
+    }
+}
+
+impl<R: RowValue> Strategy<R> for TwoPhase {
+    type Objective = PlainObjectiveValue<R>;
+
+    fn solve(
+        &self,
+        problem: Problem<R, Self::Objective>,
+        options: &SolveOptions,
+        observer: &mut impl ProblemObserver<R, Self::Objective>,
+    ) -> SolveResult<R, Self::Objective> {
+        twophase::solve_with_options(problem, options, observer)
+    }
+}
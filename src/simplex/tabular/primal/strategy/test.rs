@@ -0,0 +1,67 @@
+use crate::simplex::linearprogram::LinearProgram;
+use crate::simplex::tabular::primal::strategy::{BigM, TwoPhase};
+use crate::simplex::tabular::{EmptyObserver, SolveStatus};
+use crate::simplex::test::frac;
+use crate::simplex::value::Value;
+use crate::simplex::{ObjectiveSense, Operator};
+
+fn make_diet_program() -> LinearProgram<Value> {
+    let mut program = LinearProgram::new(ObjectiveSense::Minimize);
+    let bread = program.add_variable("bread");
+    let milk = program.add_variable("milk");
+    program.set_objective(vec![(bread, frac(2, 1)), (milk, frac(3, 1))]);
+    program.add_constraint(
+        "energy",
+        vec![(bread, frac(3, 1)), (milk, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(6, 1),
+    );
+    program.add_constraint(
+        "protein",
+        vec![(bread, frac(1, 1)), (milk, frac(2, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(4, 1),
+    );
+    program.add_constraint(
+        "budget",
+        vec![(bread, frac(1, 1)), (milk, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(10, 1),
+    );
+    program
+}
+
+#[test]
+fn strategies_agree_on_optimum() {
+    let program = make_diet_program();
+    let big_m = program.solve_with(&BigM, &mut EmptyObserver {});
+    let two_phase = program.solve_with(&TwoPhase, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, big_m.status);
+    assert_eq!(SolveStatus::Optimal, two_phase.status);
+    assert_eq!(big_m.values, two_phase.values);
+    assert_eq!(Some(&frac(8, 5)), two_phase.value("bread"));
+    assert_eq!(Some(&frac(6, 5)), two_phase.value("milk"));
+    assert_eq!(frac(34, 5), two_phase.objective_value.0);
+}
+
+#[test]
+fn strategies_agree_on_infeasibility() {
+    let mut program = make_diet_program();
+    let bread = program.variable("bread").unwrap();
+    program.add_constraint(
+        "bread_cap",
+        vec![(bread, frac(1, 1))],
+        Operator::LESSTHANEQUAL,
+        frac(1, 1),
+    );
+    program.add_constraint(
+        "bread_floor",
+        vec![(bread, frac(1, 1))],
+        Operator::GREATERTHANEQUAL,
+        frac(2, 1),
+    );
+    let big_m = program.solve_with(&BigM, &mut EmptyObserver {});
+    let two_phase = program.solve_with(&TwoPhase, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Infeasible, big_m.status);
+    assert_eq!(SolveStatus::Infeasible, two_phase.status);
+}
@@ -6,10 +6,15 @@ mod pivot_row_idx;
 use crate::simplex::tabular::primal::mobjectivevalue::test::mvalue_from_m;
 use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
 use crate::simplex::tabular::primal::{self as sut};
-use crate::simplex::tabular::{self, EmptyObserver, Equation, ProblemObserver, SimplexRow};
-use crate::simplex::test::{equality_constraint, frac, upper_bound_constraint};
+use crate::simplex::tabular::{
+    self, EmptyObserver, Equation, ProblemObserver, SimplexRow, SolveOptions, SolveStatus,
+};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, tableau, upper_bound_constraint,
+};
 use crate::simplex::value;
 use crate::simplex::value::Value;
+use crate::simplex::ObjectiveSense;
 
 struct MockObserver {
     observations: Vec<sut::PrimalProblem>,
@@ -34,7 +39,7 @@ fn solves_one_variable_zero_constraint_problem() {
     let objective_function = vec![frac(1, 1)];
     let fn_constraints = vec![];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
     let expected_solns = vec![frac(0, 1)];
     assert_eq!(expected_solns, solns);
 }
@@ -44,7 +49,7 @@ fn solves_two_variable_zero_constraint_problem() {
     let objective_function = vec![frac(1, 1), frac(2, 1)];
     let fn_constraints = vec![];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
     let expected_solns = vec![frac(0, 1), frac(0, 1)];
     assert_eq!(expected_solns, solns);
 }
@@ -55,8 +60,8 @@ fn solves_one_variable_one_constraint_problem() {
     let functional_constraint = upper_bound_constraint(vec![frac(1, 1)], frac(1, 1));
     let fn_constraints = vec![functional_constraint];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(1, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(1, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -66,8 +71,8 @@ fn maximizes_unconstrainted_variable() {
     let functional_constraint = upper_bound_constraint(vec![frac(3, 1), frac(0, 1)], frac(6, 1));
     let fn_constraints = vec![functional_constraint];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(2, 1), frac(0, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(2, 1), frac(0, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -77,8 +82,8 @@ fn maximizes_one_variable_problem() {
     let functional_constraint = upper_bound_constraint(vec![frac(3, 1)], frac(6, 1));
     let fn_constraints = vec![functional_constraint];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(2, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(2, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -89,8 +94,8 @@ fn maximizes_one_variable_two_constraint_problem() {
     let fn_constaint_1 = upper_bound_constraint(vec![frac(3, 1)], frac(6, 1));
     let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(2, 1), frac(2, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(2, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -101,8 +106,8 @@ fn maximizes_two_independent_variable_two_constraint_problem() {
     let fn_constaint_1 = upper_bound_constraint(vec![frac(0, 1), frac(4, 1)], frac(8, 1));
     let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(3, 1), frac(2, 1), frac(0, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(3, 1), frac(2, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -112,8 +117,8 @@ fn maximizes_two_dependent_variable_one_constraint_symmetric_problem() {
     let fn_constaint_0 = upper_bound_constraint(vec![frac(2, 1), frac(4, 1)], frac(6, 1));
     let fn_constraints = vec![fn_constaint_0];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(3, 1), frac(0, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(3, 1), frac(0, 1)];
     assert_eq!(expected_solns, solns);
 }
 
@@ -124,15 +129,15 @@ fn maximizes_two_dependent_variable_two_constraint_symmetric_problem() {
     let fn_constaint_1 = upper_bound_constraint(vec![frac(2, 1), frac(1, 1)], frac(3, 1));
     let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(1, 1), frac(1, 1), frac(0, 1), frac(0, 1)];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(1, 1), frac(1, 1)];
     assert_eq!(expected_solns, solns);
 }
 
 #[test]
 fn solves_big_m_problem() {
-    let problem = sut::PrimalProblem {
-        objective_equation: sut::MObjectiveEquation {
+    let mut problem = tableau(
+        sut::MObjectiveEquation {
             coefficients: vec![
                 mvalue_from_m(-frac(2, 1), -frac(2, 1)),
                 mvalue_from_m(-frac(3, 1), -frac(1, 1)),
@@ -141,7 +146,7 @@ fn solves_big_m_problem() {
             ],
             constraint: mvalue_from_m(value::zero(), -frac(3, 1)),
         },
-        rows: vec![
+        vec![
             SimplexRow {
                 basic_variable: 2,
                 equation: Equation {
@@ -159,13 +164,144 @@ fn solves_big_m_problem() {
                 ratio: value::zero(),
             },
         ],
-        point: vec![value::zero(), value::zero(), frac(4, 1), frac(3, 1)],
-    };
-    let solns = sut::solve(problem, &mut EmptyObserver::new());
-    let expected_solns = vec![frac(3, 1), value::zero(), frac(1, 1), value::zero()];
+        vec![value::zero(), value::zero(), frac(4, 1), frac(3, 1)],
+    );
+    problem.decision_var_count = 2;
+    problem.artificial_variables = vec![3];
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(3, 1), value::zero()];
     assert_eq!(expected_solns, solns);
 }
 
+#[test]
+fn reports_optimal_solve_result() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constaint_0 = upper_bound_constraint(vec![frac(1, 1), frac(2, 1)], frac(3, 1));
+    let fn_constaint_1 = upper_bound_constraint(vec![frac(2, 1), frac(1, 1)], frac(3, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(2, 1)), result.objective_value);
+    assert_eq!(vec![frac(1, 1), frac(1, 1)], result.point);
+    assert_eq!(vec![1, 0], result.basis);
+    assert_eq!(2, result.iterations);
+}
+
+#[test]
+fn minimizes_objective() {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let fn_constaint_0 = lower_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1));
+    let fn_constaint_1 = lower_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(6, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &fn_constraints,
+    );
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(MObjectiveValue::from(frac(9, 1)), result.objective_value);
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], result.point);
+}
+
+#[test]
+fn minimizes_objective_with_negative_costs() {
+    let objective_function = vec![-frac(1, 1), frac(1, 1)];
+    let functional_constraint = upper_bound_constraint(vec![frac(2, 1), frac(1, 1)], frac(6, 1));
+    let fn_constraints = vec![functional_constraint];
+    let problem = sut::PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &fn_constraints,
+    );
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(-MObjectiveValue::from(frac(3, 1)), result.objective_value);
+    assert_eq!(vec![frac(3, 1), value::zero()], result.point);
+}
+
+#[test]
+fn creates_minimization_problem_from_negated_objective() {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let functional_constraint = upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1));
+    let fn_constraints = vec![functional_constraint];
+    let problem = sut::PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_function,
+        &fn_constraints,
+    );
+    let expected_objective_equation = sut::MObjectiveEquation {
+        coefficients: vec![
+            MObjectiveValue::from(frac(2, 1)),
+            MObjectiveValue::from(frac(3, 1)),
+            MObjectiveValue::zero(),
+        ],
+        constraint: MObjectiveValue::zero(),
+    };
+    assert_eq!(expected_objective_equation, problem.objective_equation);
+    assert_eq!(ObjectiveSense::Minimize, problem.sense);
+}
+
+#[test]
+fn reports_unbounded_problem() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let functional_constraint = upper_bound_constraint(vec![frac(1, 1), -frac(1, 1)], frac(2, 1));
+    let fn_constraints = vec![functional_constraint];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Unbounded, result.status);
+}
+
+#[test]
+fn reports_infeasible_greater_than_constraint() {
+    let objective_function = vec![frac(1, 1)];
+    let fn_constaint_0 = upper_bound_constraint(vec![frac(1, 1)], frac(2, 1));
+    let fn_constaint_1 = lower_bound_constraint(vec![frac(1, 1)], frac(4, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.infeasible_constraints);
+}
+
+#[test]
+fn reports_infeasible_equality_constraint() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constaint_0 = equality_constraint(vec![frac(1, 1), frac(1, 1)], frac(5, 1));
+    let fn_constaint_1 = upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(3, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![0], result.infeasible_constraints);
+}
+
+#[test]
+fn reports_no_infeasible_constraints_when_optimal() {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let fn_constaint_0 = equality_constraint(vec![frac(1, 1), frac(1, 1)], frac(3, 1));
+    let fn_constaint_1 = lower_bound_constraint(vec![frac(1, 1), -frac(1, 1)], frac(1, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let result = sut::solve(problem, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(Vec::<usize>::new(), result.infeasible_constraints);
+    assert_eq!(vec![frac(2, 1), frac(1, 1)], result.point);
+}
+
+#[test]
+fn stops_at_iteration_limit() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constaint_0 = upper_bound_constraint(vec![frac(1, 1), frac(2, 1)], frac(3, 1));
+    let fn_constaint_1 = upper_bound_constraint(vec![frac(2, 1), frac(1, 1)], frac(3, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let options = SolveOptions { iteration_limit: 1 };
+    let result = sut::solve_with_options(problem, &options, &mut EmptyObserver::new());
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(1, result.iterations);
+}
+
 #[test]
 fn creates_big_m_problem() {
     let objective_function = vec![frac(2, 1), frac(3, 1)];
@@ -174,8 +310,8 @@ fn creates_big_m_problem() {
     let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
 
     let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
-    let expected_problem = sut::PrimalProblem {
-        objective_equation: sut::MObjectiveEquation {
+    let mut expected_problem = tableau(
+        sut::MObjectiveEquation {
             coefficients: vec![
                 mvalue_from_m(-frac(2, 1), -frac(1, 1)),
                 mvalue_from_m(-frac(3, 1), -frac(2, 1)),
@@ -184,7 +320,7 @@ fn creates_big_m_problem() {
             ],
             constraint: mvalue_from_m(value::zero(), -frac(3, 1)),
         },
-        rows: vec![
+        vec![
             SimplexRow {
                 basic_variable: 2,
                 equation: Equation {
@@ -202,8 +338,10 @@ fn creates_big_m_problem() {
                 ratio: value::zero(),
             },
         ],
-        point: vec![value::zero(), value::zero(), frac(4, 1), frac(3, 1)],
-    };
+        vec![value::zero(), value::zero(), frac(4, 1), frac(3, 1)],
+    );
+    expected_problem.decision_var_count = 2;
+    expected_problem.artificial_variables = vec![3];
     assert_eq!(
         expected_problem.objective_equation,
         problem.objective_equation
@@ -213,6 +351,155 @@ fn creates_big_m_problem() {
     assert_eq!(expected_problem, problem);
 }
 
+#[test]
+fn creates_big_m_problem_with_two_equalities() {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let fn_constaint_0 = equality_constraint(vec![frac(1, 1), frac(1, 1)], frac(3, 1));
+    let fn_constaint_1 = equality_constraint(vec![frac(1, 1), -frac(1, 1)], frac(1, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let expected_objective_equation = sut::MObjectiveEquation {
+        coefficients: vec![
+            mvalue_from_m(-frac(2, 1), -frac(2, 1)),
+            mvalue_from_m(-frac(3, 1), value::zero()),
+            MObjectiveValue::zero(),
+            MObjectiveValue::zero(),
+        ],
+        constraint: mvalue_from_m(value::zero(), -frac(4, 1)),
+    };
+    assert_eq!(expected_objective_equation, problem.objective_equation);
+}
+
+#[test]
+fn solves_problem_with_two_equalities() {
+    let objective_function = vec![frac(2, 1), frac(3, 1)];
+    let fn_constaint_0 = equality_constraint(vec![frac(1, 1), frac(1, 1)], frac(3, 1));
+    let fn_constaint_1 = equality_constraint(vec![frac(1, 1), -frac(1, 1)], frac(1, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(2, 1), frac(1, 1)];
+    assert_eq!(expected_solns, solns);
+}
+
+#[test]
+fn solves_problem_with_three_equalities() {
+    let objective_function = vec![frac(1, 1), frac(1, 1), frac(1, 1)];
+    let fn_constaint_0 =
+        equality_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(6, 1));
+    let fn_constaint_1 =
+        equality_constraint(vec![frac(1, 1), frac(1, 1), value::zero()], frac(3, 1));
+    let fn_constaint_2 =
+        equality_constraint(vec![value::zero(), frac(1, 1), frac(1, 1)], frac(5, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1, fn_constaint_2];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(1, 1), frac(2, 1), frac(3, 1)];
+    assert_eq!(expected_solns, solns);
+}
+
+#[test]
+fn solves_problem_with_two_equalities_and_upper_bound() {
+    let objective_function = vec![frac(1, 1), frac(1, 1), frac(1, 1)];
+    let fn_constaint_0 =
+        upper_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(10, 1));
+    let fn_constaint_1 =
+        equality_constraint(vec![frac(1, 1), frac(1, 1), value::zero()], frac(4, 1));
+    let fn_constaint_2 =
+        equality_constraint(vec![value::zero(), frac(1, 1), frac(1, 1)], frac(5, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1, fn_constaint_2];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![frac(4, 1), value::zero(), frac(5, 1)];
+    assert_eq!(expected_solns, solns);
+}
+
+#[test]
+fn creates_big_m_problem_with_surplus_variable() {
+    let objective_function = vec![-frac(1, 1), -frac(1, 1)];
+    let fn_constaint_0 = lower_bound_constraint(vec![frac(1, 1), frac(2, 1)], frac(4, 1));
+    let fn_constaint_1 = upper_bound_constraint(vec![frac(1, 1), frac(0, 1)], frac(3, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let mut expected_problem = tableau(
+        sut::MObjectiveEquation {
+            coefficients: vec![
+                mvalue_from_m(frac(1, 1), -frac(1, 1)),
+                mvalue_from_m(frac(1, 1), -frac(2, 1)),
+                MObjectiveValue::zero(),
+                MObjectiveValue::zero(),
+                mvalue_from_m(value::zero(), frac(1, 1)),
+            ],
+            constraint: mvalue_from_m(value::zero(), -frac(4, 1)),
+        },
+        vec![
+            SimplexRow {
+                basic_variable: 2,
+                equation: Equation {
+                    coefficients: vec![
+                        frac(1, 1),
+                        frac(2, 1),
+                        frac(1, 1),
+                        value::zero(),
+                        -frac(1, 1),
+                    ],
+                    constraint: frac(4, 1),
+                },
+                ratio: value::zero(),
+            },
+            SimplexRow {
+                basic_variable: 3,
+                equation: Equation {
+                    coefficients: vec![
+                        frac(1, 1),
+                        value::zero(),
+                        value::zero(),
+                        frac(1, 1),
+                        value::zero(),
+                    ],
+                    constraint: frac(3, 1),
+                },
+                ratio: value::zero(),
+            },
+        ],
+        vec![
+            value::zero(),
+            value::zero(),
+            frac(4, 1),
+            frac(3, 1),
+            value::zero(),
+        ],
+    );
+    expected_problem.decision_var_count = 2;
+    expected_problem.artificial_variables = vec![2];
+    assert_eq!(expected_problem, problem);
+}
+
+#[test]
+fn solves_problem_with_greater_than_constraint() {
+    let objective_function = vec![-frac(1, 1), -frac(1, 1)];
+    let fn_constaint_0 = lower_bound_constraint(vec![frac(1, 1), frac(2, 1)], frac(4, 1));
+    let fn_constaint_1 = upper_bound_constraint(vec![frac(1, 1), frac(0, 1)], frac(3, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    let expected_solns = vec![value::zero(), frac(2, 1)];
+    assert_eq!(expected_solns, solns);
+}
+
+#[test]
+fn solves_problem_with_binding_greater_than_constraints() {
+    let objective_function = vec![-frac(2, 1), -frac(3, 1)];
+    let fn_constaint_0 = lower_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1));
+    let fn_constaint_1 = lower_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(6, 1));
+    let fn_constraints = vec![fn_constaint_0, fn_constaint_1];
+    let problem = sut::PrimalProblem::new(&objective_function, &fn_constraints);
+    let solns = sut::solve(problem, &mut EmptyObserver::new()).point;
+    assert_eq!(vec![frac(3, 1), frac(1, 1)], solns);
+}
+
 #[test]
 fn solve_observes_empty_problem() {
     let mut observer = MockObserver::new();
@@ -234,10 +521,10 @@ fn solve_observes_steps_of_problem() {
     let mut middle_problem = problem.clone();
     sut::set_ratios(&mut middle_problem, 0);
     let mut solved_problem = middle_problem.clone();
-    sut::set_basic_variable(&mut solved_problem, 0, 0);
+    tabular::set_basic_variable(&mut solved_problem, 0, 0);
     tabular::normalize_equation(&mut solved_problem, 0, 0);
     tabular::reduce_equations(&mut solved_problem, 0, 0);
-    sut::set_new_point(&mut solved_problem);
+    tabular::set_new_point(&mut solved_problem);
 
     let expected_observations = vec![middle_problem, solved_problem];
     let _solution = sut::solve(problem, &mut observer);
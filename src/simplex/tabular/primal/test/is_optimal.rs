@@ -1,83 +1,99 @@
 mod is_optimal {
     use crate::simplex::tabular::primal::{
-        self as sut, MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from
+        self as sut, MObjectiveEquation, mobjectivevalue::test::mvalue_from
     };
+    use crate::simplex::test::tableau;
 
     #[test]
     fn empty_objective_is_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(sut::is_optimal(&problem));
     }
 
     #[test]
     fn positive_objective_is_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(1, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(sut::is_optimal(&problem));
     }
 
     #[test]
     fn zero_objective_is_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(sut::is_optimal(&problem));
     }
 
     #[test]
     fn positive_and_zero_objective_is_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(1, 1), mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(sut::is_optimal(&problem));
     }
 
     #[test]
     fn negative_objective_is_not_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![-mvalue_from(1, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(!sut::is_optimal(&problem));
     }
 
     #[test]
     fn one_negative_in_objective_is_not_optimal() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![-mvalue_from(1, 1), mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert!(!sut::is_optimal(&problem));
     }
+
+    #[test]
+    fn negative_artificial_objective_is_optimal() {
+        let mut problem = tableau(
+            MObjectiveEquation {
+                coefficients: vec![mvalue_from(1, 1), -mvalue_from(1, 1)],
+                constraint: mvalue_from(0, 1),
+            },
+            vec![],
+            vec![],
+        );
+        problem.decision_var_count = 1;
+        problem.artificial_variables = vec![1];
+        assert!(sut::is_optimal(&problem));
+    }
 }
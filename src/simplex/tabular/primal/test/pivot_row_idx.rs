@@ -1,33 +1,33 @@
 mod pivot_row_idx {
     use crate::simplex::{
         tabular::{Equation, SimplexRow, primal::{
-            self as sut, MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from
+            self as sut, MObjectiveEquation, mobjectivevalue::test::mvalue_from
         }},
-        test::frac,
+        test::{frac, tableau},
     };
 
     #[test]
     fn returns_none_for_no_rows() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
+            vec![],
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
         assert_eq!(None, result);
     }
 
     #[test]
     fn returns_index_for_single_positive_ratio() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1)],
@@ -35,20 +35,20 @@ mod pivot_row_idx {
                 },
                 ratio: frac(5, 1),
             }],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
         assert_eq!(Some(0), result);
     }
 
     #[test]
-    fn returns_none_for_zero_ratio() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+    fn returns_index_for_zero_ratio() {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1)],
@@ -56,20 +56,20 @@ mod pivot_row_idx {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
-        assert_eq!(None, result);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
+        assert_eq!(Some(0), result);
     }
 
     #[test]
     fn returns_none_for_negative_ratio() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1)],
@@ -77,20 +77,20 @@ mod pivot_row_idx {
                 },
                 ratio: -frac(5, 1),
             }],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
         assert_eq!(None, result);
     }
 
     #[test]
     fn returns_index_of_minimum_positive_ratio() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -116,20 +116,20 @@ mod pivot_row_idx {
                     ratio: frac(7, 1),
                 },
             ],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
         assert_eq!(Some(1), result);
     }
 
     #[test]
-    fn ignores_non_positive_ratios() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+    fn prefers_zero_ratio_and_ignores_negative_ratios() {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -155,20 +155,20 @@ mod pivot_row_idx {
                     ratio: frac(5, 1),
                 },
             ],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
-        assert_eq!(Some(2), result);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
+        assert_eq!(Some(1), result);
     }
 
     #[test]
-    fn returns_none_when_all_ratios_non_positive() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+    fn returns_zero_ratio_when_other_ratios_negative() {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -186,9 +186,48 @@ mod pivot_row_idx {
                     ratio: frac(0, 1),
                 },
             ],
-            point: vec![],
-        };
-        let result = sut::pivot_row_idx(&problem);
-        assert_eq!(None, result);
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
+        assert_eq!(Some(1), result);
+    }
+
+    #[test]
+    fn ignores_rows_with_non_positive_pivot_coefficient() {
+        let problem = tableau(
+            MObjectiveEquation {
+                coefficients: vec![],
+                constraint: mvalue_from(0, 1),
+            },
+            vec![
+                SimplexRow {
+                    basic_variable: 0,
+                    equation: Equation {
+                        coefficients: vec![-frac(1, 1)],
+                        constraint: frac(0, 1),
+                    },
+                    ratio: frac(0, 1),
+                },
+                SimplexRow {
+                    basic_variable: 1,
+                    equation: Equation {
+                        coefficients: vec![frac(0, 1)],
+                        constraint: frac(2, 1),
+                    },
+                    ratio: frac(2, 1) / frac(0, 1),
+                },
+                SimplexRow {
+                    basic_variable: 2,
+                    equation: Equation {
+                        coefficients: vec![frac(2, 1)],
+                        constraint: frac(8, 1),
+                    },
+                    ratio: frac(4, 1),
+                },
+            ],
+            vec![],
+        );
+        let result = sut::pivot_row_idx(&problem, 0);
+        assert_eq!(Some(2), result);
     }
 }
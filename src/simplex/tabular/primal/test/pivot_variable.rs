@@ -1,44 +1,60 @@
 mod pivot_variable {
     use crate::simplex::tabular::primal::{
-        self as sut, MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from
+        self as sut, MObjectiveEquation, mobjectivevalue::test::mvalue_from
     };
+    use crate::simplex::test::tableau;
 
     #[test]
     fn empty_objective_has_no_pivot() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert_eq!(None, sut::pivot_variable(&problem));
     }
 
     #[test]
     fn single_var_is_pivot() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(1, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert_eq!(Some(0), sut::pivot_variable(&problem));
     }
 
     #[test]
     fn smallest_var_is_pivot() {
-        let problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(1, 1), -mvalue_from(2, 1), mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![],
-            point: vec![],
-        };
+            vec![],
+            vec![],
+        );
         assert_eq!(Some(1), sut::pivot_variable(&problem));
     }
+
+    #[test]
+    fn artificial_var_is_never_pivot() {
+        let mut problem = tableau(
+            MObjectiveEquation {
+                coefficients: vec![-mvalue_from(1, 1), -mvalue_from(2, 1)],
+                constraint: mvalue_from(0, 1),
+            },
+            vec![],
+            vec![],
+        );
+        problem.decision_var_count = 1;
+        problem.artificial_variables = vec![1];
+        assert_eq!(Some(0), sut::pivot_variable(&problem));
+    }
 }
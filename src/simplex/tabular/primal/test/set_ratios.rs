@@ -1,19 +1,19 @@
 mod set_ratios {
     use crate::simplex::{
         tabular::{Equation, SimplexRow, primal::{
-            self as sut, MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from
+            self as sut, MObjectiveEquation, mobjectivevalue::test::mvalue_from
         }},
-        test::frac,
+        test::{frac, tableau},
     };
 
     #[test]
     fn recalculates_ratio_for_row() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(2, 1)],
@@ -21,20 +21,20 @@ mod set_ratios {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::set_ratios(&mut problem, 0);
         assert_eq!(frac(3, 1), problem.rows[0].ratio);
     }
 
     #[test]
     fn recalculates_ratio_for_rows() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -52,8 +52,8 @@ mod set_ratios {
                     ratio: frac(0, 1),
                 },
             ],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::set_ratios(&mut problem, 0);
         assert_eq!(frac(3, 1), problem.rows[0].ratio);
         assert_eq!(frac(4, 1), problem.rows[1].ratio);
@@ -61,12 +61,12 @@ mod set_ratios {
 
     #[test]
     fn recalculates_ratio_for_specified_var() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(2, 1), frac(3, 1)],
@@ -74,8 +74,8 @@ mod set_ratios {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::set_ratios(&mut problem, 1);
         assert_eq!(frac(2, 1), problem.rows[0].ratio);
     }
@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod test;
+
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg};
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::{Row, RowValue};
+use crate::simplex::tabular::primal::pivotrule::{Dantzig, PivotRule};
+use crate::simplex::tabular::{
+    self, primal, Problem, ProblemObserver, SolveOptions, SolveResult, SolveStatus,
+};
+use crate::simplex::{Coefficients, Constraint, ObjectiveSense};
+
+/// An objective value with no big-M part. The initial objective equation
+/// ignores artificial variables, so it suits problems without `>=` or `=`
+/// rows, or two-phase solves, which drive the artificials out first.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PlainObjectiveValue<R: RowValue>(pub R);
+
+pub type TwoPhaseProblem<R> = Problem<R, PlainObjectiveValue<R>>;
+
+impl<R: RowValue> Add for PlainObjectiveValue<R> {
+    type Output = PlainObjectiveValue<R>;
+
+    fn add(self, rhs: PlainObjectiveValue<R>) -> Self::Output {
+        PlainObjectiveValue(self.0 + rhs.0)
+    }
+}
+
+impl<R: RowValue> Add<R> for PlainObjectiveValue<R> {
+    type Output = PlainObjectiveValue<R>;
+
+    fn add(self, rhs: R) -> Self::Output {
+        PlainObjectiveValue(self.0 + rhs)
+    }
+}
+
+impl<R: RowValue> Neg for PlainObjectiveValue<R> {
+    type Output = PlainObjectiveValue<R>;
+
+    fn neg(self) -> Self::Output {
+        PlainObjectiveValue(-self.0)
+    }
+}
+
+impl<R: RowValue> Mul<R> for PlainObjectiveValue<R> {
+    type Output = PlainObjectiveValue<R>;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        PlainObjectiveValue(self.0 * rhs)
+    }
+}
+
+impl<R: RowValue> Div<R> for PlainObjectiveValue<R> {
+    type Output = PlainObjectiveValue<R>;
+
+    fn div(self, rhs: R) -> Self::Output {
+        PlainObjectiveValue(self.0 / rhs)
+    }
+}
+
+impl<R: RowValue> Display for PlainObjectiveValue<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<R: RowValue> ObjectiveValue<R> for PlainObjectiveValue<R> {
+    fn zero() -> Self {
+        PlainObjectiveValue(R::zero())
+    }
+
+    fn is_optimal(&self) -> bool {
+        !self.0.is_improving()
+    }
+
+    fn finite_part(&self) -> R {
+        self.0.clone()
+    }
+
+    fn artificial_penalty() -> Self {
+        PlainObjectiveValue(R::zero())
+    }
+
+    fn initial_objective_equation(
+        objective_fn_coeffs: &Coefficients<R>,
+        functional_constraints: &[Constraint<R>],
+    ) -> Row<Self> {
+        let aux_var_count =
+            functional_constraints.len() + tabular::surplus_var_count(functional_constraints);
+        Row {
+            coefficients: objective_fn_coeffs
+                .iter()
+                .map(|coeff| PlainObjectiveValue(-coeff.clone()))
+                .chain(std::iter::repeat_n(
+                    PlainObjectiveValue(R::zero()),
+                    aux_var_count,
+                ))
+                .collect(),
+            constraint: PlainObjectiveValue(R::zero()),
+        }
+    }
+}
+
+pub fn solve<R: RowValue>(
+    problem: TwoPhaseProblem<R>,
+    observer: &mut impl ProblemObserver<R, PlainObjectiveValue<R>>,
+) -> SolveResult<R, PlainObjectiveValue<R>> {
+    solve_with_options(problem, &SolveOptions::default(), observer)
+}
+
+pub fn solve_with_options<R: RowValue>(
+    problem: TwoPhaseProblem<R>,
+    options: &SolveOptions,
+    observer: &mut impl ProblemObserver<R, PlainObjectiveValue<R>>,
+) -> SolveResult<R, PlainObjectiveValue<R>> {
+    solve_with_rule(problem, &Dantzig, options, observer)
+}
+
+/// Phase I minimizes the sum of the artificial variables. If it reaches
+/// zero, phase II optimizes the real objective from the feasible basis
+/// phase I found, with the artificial variables out of the basis. An
+/// infeasible result reports phase I's objective value: minus the total
+/// infeasibility.
+pub fn solve_with_rule<R: RowValue>(
+    problem: TwoPhaseProblem<R>,
+    rule: &impl PivotRule<R, PlainObjectiveValue<R>>,
+    options: &SolveOptions,
+    observer: &mut impl ProblemObserver<R, PlainObjectiveValue<R>>,
+) -> SolveResult<R, PlainObjectiveValue<R>> {
+    let objective_equation = problem.objective_equation.clone();
+    let sense = problem.sense;
+    let phase_one = Problem {
+        objective_equation: phase_one_objective(&problem),
+        sense: ObjectiveSense::Maximize,
+        ..problem
+    };
+    let phase_one_result = primal::solve_with_rule(phase_one, rule, options, observer);
+    if phase_one_result.status != SolveStatus::Optimal {
+        return phase_one_result;
+    }
+    let mut phase_two = phase_one_result.tableau;
+    drive_out_artificials(&mut phase_two);
+    phase_two.objective_equation = priced_out(objective_equation, &phase_two);
+    phase_two.sense = sense;
+    let remaining = SolveOptions {
+        iteration_limit: options.iteration_limit - phase_one_result.iterations,
+    };
+    let mut result = primal::solve_with_rule(phase_two, rule, &remaining, observer);
+    result.iterations += phase_one_result.iterations;
+    result
+}
+
+/// Each artificial variable costs one unit, priced out of the initial
+/// basis.
+fn phase_one_objective<R: RowValue>(problem: &TwoPhaseProblem<R>) -> Row<PlainObjectiveValue<R>> {
+    let coefficients = (0..problem.point.len())
+        .map(|var| {
+            if problem.artificial_variables.contains(&var) {
+                PlainObjectiveValue(R::one())
+            } else {
+                PlainObjectiveValue(R::zero())
+            }
+        })
+        .collect();
+    let objective = Row {
+        coefficients,
+        constraint: PlainObjectiveValue(R::zero()),
+    };
+    priced_out(objective, problem)
+}
+
+/// Rewrites an objective equation in terms of the current basis, leaving
+/// a zero under every basic variable.
+fn priced_out<R: RowValue>(
+    mut objective: Row<PlainObjectiveValue<R>>,
+    problem: &TwoPhaseProblem<R>,
+) -> Row<PlainObjectiveValue<R>> {
+    for row in &problem.rows {
+        tabular::reduce_row(&mut objective, &row.equation, row.basic_variable);
+    }
+    objective
+}
+
+/// Artificial variables left basic at zero are swapped for any other
+/// variable in their row. A row with no other variable is redundant, and
+/// its artificial variable stays basic at zero.
+fn drive_out_artificials<R: RowValue>(problem: &mut TwoPhaseProblem<R>) {
+    for row_idx in 0..problem.rows.len() {
+        let row = &problem.rows[row_idx];
+        if !problem.artificial_variables.contains(&row.basic_variable) {
+            continue;
+        }
+        let replacement = (0..row.equation.coefficients.len()).find(|var| {
+            !problem.artificial_variables.contains(var)
+                && row.equation.coefficients[*var] != R::zero()
+        });
+        if let Some(var) = replacement {
+            tabular::pivot(problem, row_idx, var);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::tabular::primal::twophase::{self as sut, PlainObjectiveValue};
+use crate::simplex::tabular::{EmptyObserver, SolveOptions, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value::{self, Value};
+use crate::simplex::ObjectiveSense;
+
+fn plain(n: u64, d: u64) -> PlainObjectiveValue<Value> {
+    PlainObjectiveValue(frac(n, d))
+}
+
+#[test]
+fn plain_value_is_optimal_when_non_negative() {
+    assert!(plain(0, 1).is_optimal());
+    assert!(plain(1, 2).is_optimal());
+    assert!(!(-plain(1, 2)).is_optimal());
+    assert_eq!(plain(3, 4), plain(1, 2) * frac(3, 2));
+}
+
+#[test]
+fn solves_problem_without_artificial_rows() {
+    let problem = sut::TwoPhaseProblem::new(
+        &vec![frac(1, 1), frac(2, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+            upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(8, 1)),
+        ],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(plain(6, 1), result.objective_value);
+    assert_eq!(vec![frac(2, 1), frac(2, 1)], result.point);
+    assert_eq!(2, result.iterations);
+}
+
+#[test]
+fn solves_artificial_rows_in_two_phases() {
+    let problem = sut::TwoPhaseProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &vec![frac(2, 1), frac(3, 1), frac(4, 1)],
+        &[
+            lower_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(4, 1)),
+            equality_constraint(vec![frac(1, 1), -frac(1, 1), value::zero()], value::zero()),
+        ],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(plain(10, 1), result.objective_value);
+    assert_eq!(vec![frac(2, 1), frac(2, 1), value::zero()], result.point);
+    assert_eq!(vec![frac(5, 2), -frac(1, 2)], result.dual_values);
+    assert!(result
+        .basis
+        .iter()
+        .all(|var| !result.tableau.artificial_variables.contains(var)));
+}
+
+#[test]
+fn reports_infeasibility_from_phase_one() {
+    let problem = sut::TwoPhaseProblem::new(
+        &vec![frac(1, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1)], frac(1, 1)),
+            lower_bound_constraint(vec![frac(1, 1)], frac(2, 1)),
+        ],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.infeasible_constraints);
+    assert_eq!(-plain(1, 1), result.objective_value);
+}
+
+#[test]
+fn keeps_artificial_of_redundant_row_basic_at_zero() {
+    let problem = sut::TwoPhaseProblem::new(
+        &vec![frac(1, 1), value::zero()],
+        &[
+            equality_constraint(vec![frac(1, 1), frac(1, 1)], frac(2, 1)),
+            equality_constraint(vec![frac(2, 1), frac(2, 1)], frac(4, 1)),
+        ],
+    );
+    let result = sut::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(2, 1), value::zero()], result.point);
+    assert_eq!(plain(2, 1), result.objective_value);
+}
+
+#[test]
+fn counts_iterations_of_both_phases_against_limit() {
+    let problem = || {
+        sut::TwoPhaseProblem::new(
+            &vec![frac(1, 1), frac(1, 1)],
+            &[
+                lower_bound_constraint(vec![frac(1, 1), value::zero()], frac(1, 1)),
+                upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(3, 1)),
+            ],
+        )
+    };
+    let result = sut::solve(problem(), &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    let options = SolveOptions {
+        iteration_limit: result.iterations - 1,
+    };
+    let limited = sut::solve_with_options(problem(), &options, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::IterationLimit, limited.status);
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod test;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::RowValue;
+use crate::simplex::tabular::{self, Problem};
+use crate::simplex::{ObjectiveSense, Variable};
+
+/// Shadow prices and reduced costs read off the objective row of a final
+/// tableau, stated in the problem's own objective sense.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Sensitivity<R: RowValue> {
+    /// Change in the objective per unit increase of each constraint's bound.
+    /// Nonzero only for binding constraints.
+    pub shadow_prices: Vec<R>,
+    /// Change in the objective per unit increase of each decision variable.
+    /// Zero for basic variables.
+    pub reduced_costs: Vec<R>,
+    /// How far each objective coefficient can move before the optimal basis
+    /// changes.
+    pub objective_ranges: Vec<Range<R>>,
+    /// How far each constraint's bound can move before its shadow price
+    /// changes.
+    pub bound_ranges: Vec<Range<R>>,
+}
+
+/// Allowable changes to a value around its current one. `None` means the
+/// value can move without limit in that direction.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Range<R: RowValue> {
+    pub allowable_increase: Option<R>,
+    pub allowable_decrease: Option<R>,
+}
+
+pub fn analyze<R: RowValue, O: ObjectiveValue<R>>(problem: &Problem<R, O>) -> Sensitivity<R> {
+    let reduced_costs = problem.objective_equation.coefficients[..problem.decision_var_count]
+        .iter()
+        .map(|coeff| -coeff.finite_part());
+    Sensitivity {
+        shadow_prices: tabular::dual_values(problem),
+        reduced_costs: reduced_costs.map(|d| in_sense(problem.sense, d)).collect(),
+        objective_ranges: (0..problem.decision_var_count)
+            .map(|var| objective_range(problem, var))
+            .collect(),
+        bound_ranges: (0..problem.rows.len())
+            .map(|idx| bound_range(problem, idx))
+            .collect(),
+    }
+}
+
+/// A nonbasic variable stays nonbasic until its coefficient makes up its
+/// reduced cost. Changing a basic variable's coefficient shifts every
+/// reduced cost in proportion to that variable's row, and the basis holds
+/// while they all stay optimal.
+fn objective_range<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    var: Variable,
+) -> Range<R> {
+    let coefficients = &problem.objective_equation.coefficients;
+    let range = match problem.rows.iter().find(|row| row.basic_variable == var) {
+        None => Range {
+            allowable_increase: Some(coefficients[var].finite_part()),
+            allowable_decrease: None,
+        },
+        Some(row) => {
+            let steps = || {
+                nonbasic_columns(problem).map(|col| {
+                    (
+                        coefficients[col].finite_part(),
+                        row.equation.coefficients[col].clone(),
+                    )
+                })
+            };
+            Range {
+                allowable_increase: steps()
+                    .filter(|(_, coeff)| *coeff < R::zero())
+                    .map(|(cost, coeff)| cost / -coeff)
+                    .min(),
+                allowable_decrease: steps()
+                    .filter(|(_, coeff)| *coeff > R::zero())
+                    .map(|(cost, coeff)| cost / coeff)
+                    .min(),
+            }
+        }
+    };
+    match problem.sense {
+        ObjectiveSense::Maximize => range,
+        ObjectiveSense::Minimize => Range {
+            allowable_increase: range.allowable_decrease,
+            allowable_decrease: range.allowable_increase,
+        },
+    }
+}
+
+/// Changing a bound moves the basic solution along the constraint's
+/// initial unit column, and the basis holds while the solution stays
+/// nonnegative.
+fn bound_range<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+    constraint_idx: usize,
+) -> Range<R> {
+    let col = problem.decision_var_count + constraint_idx;
+    let steps = || {
+        problem.rows.iter().map(|row| {
+            (
+                row.equation.constraint.clone(),
+                row.equation.coefficients[col].clone(),
+            )
+        })
+    };
+    Range {
+        allowable_increase: steps()
+            .filter(|(_, coeff)| *coeff < R::zero())
+            .map(|(value, coeff)| value / -coeff)
+            .min(),
+        allowable_decrease: steps()
+            .filter(|(_, coeff)| *coeff > R::zero())
+            .map(|(value, coeff)| value / coeff)
+            .min(),
+    }
+}
+
+fn nonbasic_columns<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &Problem<R, O>,
+) -> impl Iterator<Item = Variable> + '_ {
+    (0..problem.objective_equation.coefficients.len()).filter(|col| {
+        !problem.artificial_variables.contains(col)
+            && problem.rows.iter().all(|row| row.basic_variable != *col)
+    })
+}
+
+/// The tableau maximizes, so its rates of change flip for minimization.
+fn in_sense<R: RowValue>(sense: ObjectiveSense, value: R) -> R {
+    match sense {
+        ObjectiveSense::Maximize => value,
+        ObjectiveSense::Minimize => -value,
+    }
+}
@@ -0,0 +1,151 @@
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::sensitivity::{self as sut};
+use crate::simplex::tabular::{EmptyObserver, SolveResult, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value;
+use crate::simplex::value::Value;
+use crate::simplex::ObjectiveSense;
+
+fn range(increase: Option<Value>, decrease: Option<Value>) -> sut::Range<Value> {
+    sut::Range {
+        allowable_increase: increase,
+        allowable_decrease: decrease,
+    }
+}
+
+// maximize x + 2y subject to x + y <= 4, x + 3y <= 8 and x <= 5, optimal
+// at (2, 2) with the first two constraints binding.
+fn solve_binding_problem() -> SolveResult<Value, MObjectiveValue> {
+    let problem = PrimalProblem::new(
+        &vec![frac(1, 1), frac(2, 1)],
+        &[
+            upper_bound_constraint(vec![frac(1, 1), frac(1, 1)], frac(4, 1)),
+            upper_bound_constraint(vec![frac(1, 1), frac(3, 1)], frac(8, 1)),
+            upper_bound_constraint(vec![frac(1, 1), value::zero()], frac(5, 1)),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    result
+}
+
+// maximize 3x + y subject to x + 2y <= 4, optimal at (4, 0) with y
+// nonbasic.
+fn solve_single_row_problem() -> SolveResult<Value, MObjectiveValue> {
+    let problem = PrimalProblem::new(
+        &vec![frac(3, 1), frac(1, 1)],
+        &[upper_bound_constraint(
+            vec![frac(1, 1), frac(2, 1)],
+            frac(4, 1),
+        )],
+    );
+    primal::solve(problem, &mut EmptyObserver {})
+}
+
+// minimize 2x + 3y + 4z subject to x + y + z >= 4 and x - y = 0, optimal
+// at (2, 2, 0) with both rows starting on artificial variables.
+fn solve_minimizing_problem() -> SolveResult<Value, MObjectiveValue> {
+    let problem = PrimalProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &vec![frac(2, 1), frac(3, 1), frac(4, 1)],
+        &[
+            lower_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(4, 1)),
+            equality_constraint(vec![frac(1, 1), -frac(1, 1), value::zero()], value::zero()),
+        ],
+    );
+    let result = primal::solve(problem, &mut EmptyObserver {});
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(vec![frac(2, 1), frac(2, 1), value::zero()], result.point);
+    result
+}
+
+#[test]
+fn reads_shadow_prices_of_binding_constraints() {
+    let sensitivity = sut::analyze(&solve_binding_problem().tableau);
+    assert_eq!(
+        vec![frac(1, 2), frac(1, 2), value::zero()],
+        sensitivity.shadow_prices
+    );
+    assert_eq!(
+        vec![value::zero(), value::zero()],
+        sensitivity.reduced_costs
+    );
+}
+
+#[test]
+fn reads_reduced_costs_of_nonbasic_variables() {
+    let sensitivity = sut::analyze(&solve_single_row_problem().tableau);
+    assert_eq!(vec![frac(3, 1)], sensitivity.shadow_prices);
+    assert_eq!(vec![value::zero(), -frac(5, 1)], sensitivity.reduced_costs);
+}
+
+#[test]
+fn reads_duals_of_artificial_rows_when_minimizing() {
+    let sensitivity = sut::analyze(&solve_minimizing_problem().tableau);
+    assert_eq!(vec![frac(5, 2), -frac(1, 2)], sensitivity.shadow_prices);
+    assert_eq!(
+        vec![value::zero(), value::zero(), frac(3, 2)],
+        sensitivity.reduced_costs
+    );
+}
+
+#[test]
+fn ranges_objective_coefficients_of_basic_variables() {
+    let sensitivity = sut::analyze(&solve_binding_problem().tableau);
+    assert_eq!(
+        vec![
+            range(Some(frac(1, 1)), Some(frac(1, 3))),
+            range(Some(frac(1, 1)), Some(frac(1, 1))),
+        ],
+        sensitivity.objective_ranges
+    );
+}
+
+#[test]
+fn ranges_bounds_of_binding_and_slack_constraints() {
+    let sensitivity = sut::analyze(&solve_binding_problem().tableau);
+    assert_eq!(
+        vec![
+            range(Some(frac(2, 1)), Some(frac(4, 3))),
+            range(Some(frac(4, 1)), Some(frac(4, 1))),
+            range(None, Some(frac(3, 1))),
+        ],
+        sensitivity.bound_ranges
+    );
+}
+
+#[test]
+fn ranges_nonbasic_objective_coefficient_up_to_its_reduced_cost() {
+    let sensitivity = sut::analyze(&solve_single_row_problem().tableau);
+    assert_eq!(
+        vec![range(None, Some(frac(5, 2))), range(Some(frac(5, 1)), None)],
+        sensitivity.objective_ranges
+    );
+    assert_eq!(
+        vec![range(None, Some(frac(4, 1)))],
+        sensitivity.bound_ranges
+    );
+}
+
+#[test]
+fn ranges_in_the_minimization_sense() {
+    let sensitivity = sut::analyze(&solve_minimizing_problem().tableau);
+    assert_eq!(
+        vec![
+            range(Some(frac(3, 1)), Some(frac(5, 1))),
+            range(Some(frac(3, 1)), Some(frac(5, 1))),
+            range(None, Some(frac(3, 2))),
+        ],
+        sensitivity.objective_ranges
+    );
+    assert_eq!(
+        vec![
+            range(None, Some(frac(4, 1))),
+            range(Some(frac(4, 1)), Some(frac(4, 1))),
+        ],
+        sensitivity.bound_ranges
+    );
+}
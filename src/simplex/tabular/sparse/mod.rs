@@ -0,0 +1,312 @@
+#[cfg(test)]
+mod test;
+
+use std::fmt::Display;
+
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::{Row, RowValue};
+use crate::simplex::sparse::{SparseCoefficients, SparseConstraint};
+use crate::simplex::tabular::{
+    self, Problem, SimplexRow, SolveOptions, SolveResult, SolveStatus, Tableau,
+};
+use crate::simplex::{Coefficients, ObjectiveSense, Operator, Variable};
+
+/// A constraint entry past the decision variables, which would land on a
+/// slack or surplus column.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ColumnOutOfRange {
+    pub constraint: usize,
+    pub column: Variable,
+    pub decision_var_count: usize,
+}
+
+impl Display for ColumnOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "constraint {} has an entry in column {}, past the {} decision variables",
+            self.constraint, self.column, self.decision_var_count
+        )
+    }
+}
+
+impl std::error::Error for ColumnOutOfRange {}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SparseEquation<R: RowValue> {
+    pub coefficients: SparseCoefficients<R>,
+    pub constraint: R,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct SparseSimplexRow<R: RowValue> {
+    pub basic_variable: Variable,
+    pub equation: SparseEquation<R>,
+}
+
+/// A tableau that stores its rows sparsely, so memory scales with the
+/// nonzeros instead of rows times columns. Each row holds a single entry
+/// for its slack or artificial column and its surplus column. The
+/// objective row stays dense.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SparseProblem<R: RowValue, O: ObjectiveValue<R>> {
+    pub objective_equation: Row<O>,
+    pub rows: Vec<SparseSimplexRow<R>>,
+    pub decision_var_count: usize,
+    pub artificial_variables: Vec<Variable>,
+    pub sense: ObjectiveSense,
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> SparseProblem<R, O> {
+    pub fn new(
+        objective_coeffs: &Coefficients<R>,
+        functional_constraints: &[SparseConstraint<R>],
+    ) -> Result<Self, ColumnOutOfRange> {
+        Self::with_sense(
+            ObjectiveSense::Maximize,
+            objective_coeffs,
+            functional_constraints,
+        )
+    }
+
+    /// Lays out the columns as `Problem::with_sense` does: decision
+    /// variables, one slack or artificial column per constraint, then one
+    /// surplus column per `>=` constraint. Fails if a constraint has an
+    /// entry past the decision variables.
+    pub fn with_sense(
+        sense: ObjectiveSense,
+        objective_coeffs: &Coefficients<R>,
+        functional_constraints: &[SparseConstraint<R>],
+    ) -> Result<Self, ColumnOutOfRange> {
+        let decision_var_count = objective_coeffs.len();
+        let first_surplus = decision_var_count + functional_constraints.len();
+        let column_count = first_surplus
+            + functional_constraints
+                .iter()
+                .filter(|constraint| constraint.operator == Operator::GREATERTHANEQUAL)
+                .count();
+        let mut rows = vec![];
+        let mut artificial_variables = vec![];
+        let mut next_surplus = first_surplus;
+        for (idx, constraint) in functional_constraints.iter().enumerate() {
+            if let Some((column, _)) = constraint
+                .coefficients
+                .entries
+                .iter()
+                .find(|(var, _)| *var >= decision_var_count)
+            {
+                return Err(ColumnOutOfRange {
+                    constraint: idx,
+                    column: *column,
+                    decision_var_count,
+                });
+            }
+            let basic_variable = decision_var_count + idx;
+            let mut coefficients = constraint.coefficients.clone();
+            coefficients.push(basic_variable, R::one());
+            if constraint.operator == Operator::GREATERTHANEQUAL {
+                coefficients.push(next_surplus, -R::one());
+                next_surplus += 1;
+            }
+            coefficients.len = column_count;
+            if constraint.operator != Operator::LESSTHANEQUAL {
+                artificial_variables.push(basic_variable);
+            }
+            rows.push(SparseSimplexRow {
+                basic_variable,
+                equation: SparseEquation {
+                    coefficients,
+                    constraint: constraint.bound.clone(),
+                },
+            });
+        }
+        let maximized_coeffs = tabular::maximized_coefficients(sense, objective_coeffs);
+        let mut objective_equation = tabular::objective_row(&maximized_coeffs, column_count);
+        for row in rows
+            .iter()
+            .filter(|row| artificial_variables.contains(&row.basic_variable))
+        {
+            tabular::price_out_artificial_variable(
+                &mut objective_equation,
+                row.basic_variable,
+                row.equation.coefficients.entries.iter().cloned(),
+                row.equation.constraint.clone(),
+            );
+        }
+        Ok(Self {
+            objective_equation,
+            rows,
+            decision_var_count,
+            artificial_variables,
+            sense,
+        })
+    }
+
+    /// The value of every column.
+    pub fn point(&self) -> Coefficients<R> {
+        let mut point = vec![R::zero(); self.objective_equation.coefficients.len()];
+        for row in &self.rows {
+            point[row.basic_variable] = row.equation.constraint.clone();
+        }
+        point
+    }
+
+    /// The same tableau with dense rows, for sensitivity analysis.
+    pub fn to_dense(&self) -> Problem<R, O> {
+        Problem {
+            objective_equation: self.objective_equation.clone(),
+            rows: self
+                .rows
+                .iter()
+                .map(|row| SimplexRow {
+                    basic_variable: row.basic_variable,
+                    equation: Row {
+                        coefficients: row.equation.coefficients.to_dense(),
+                        constraint: row.equation.constraint.clone(),
+                    },
+                    ratio: R::zero(),
+                })
+                .collect(),
+            point: self.point(),
+            decision_var_count: self.decision_var_count,
+            artificial_variables: self.artificial_variables.clone(),
+            sense: self.sense,
+        }
+    }
+}
+
+impl<R: RowValue, O: ObjectiveValue<R>> Tableau<R, O> for SparseProblem<R, O> {
+    fn sense(&self) -> ObjectiveSense {
+        self.sense
+    }
+
+    fn decision_var_count(&self) -> usize {
+        self.decision_var_count
+    }
+
+    fn artificial_variables(&self) -> &[Variable] {
+        &self.artificial_variables
+    }
+
+    fn basic_values(&self) -> Vec<(Variable, R)> {
+        self.rows
+            .iter()
+            .map(|row| (row.basic_variable, row.equation.constraint.clone()))
+            .collect()
+    }
+
+    fn objective_constant(&self) -> O {
+        self.objective_equation.constraint.clone()
+    }
+
+    fn slack_objective_coefficients(&self) -> Vec<O> {
+        let first = self.decision_var_count;
+        self.objective_equation.coefficients[first..first + self.rows.len()].to_vec()
+    }
+}
+
+pub fn solve<R: RowValue, O: ObjectiveValue<R>>(
+    problem: SparseProblem<R, O>,
+) -> SolveResult<R, O, SparseProblem<R, O>> {
+    solve_with_options(problem, &SolveOptions::default())
+}
+
+/// The primal simplex with Dantzig's rule over sparse rows. A pivot only
+/// touches the rows with a nonzero in the pivot column.
+pub fn solve_with_options<R: RowValue, O: ObjectiveValue<R>>(
+    mut problem: SparseProblem<R, O>,
+    options: &SolveOptions,
+) -> SolveResult<R, O, SparseProblem<R, O>> {
+    let mut iterations = 0;
+    while let Some(pivot_variable) = pivot_variable(&problem) {
+        if iterations == options.iteration_limit {
+            return result(problem, SolveStatus::IterationLimit, iterations);
+        }
+        let Some(pivot_row_idx) = pivot_row_idx(&problem, pivot_variable) else {
+            return result(problem, SolveStatus::Unbounded, iterations);
+        };
+        pivot(&mut problem, pivot_row_idx, pivot_variable);
+        iterations += 1;
+    }
+    result(problem, SolveStatus::Optimal, iterations)
+}
+
+/// Most negative objective row entry enters. An artificial variable never
+/// re-enters once it has left.
+fn pivot_variable<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &SparseProblem<R, O>,
+) -> Option<Variable> {
+    problem
+        .objective_equation
+        .coefficients
+        .iter()
+        .enumerate()
+        .filter(|(var, value)| !problem.artificial_variables.contains(var) && !value.is_optimal())
+        .min_by(|(_, v1), (_, v2)| v1.cmp(v2))
+        .unzip()
+        .0
+}
+
+/// Smallest ratio among the rows with a positive entry in the pivot column.
+fn pivot_row_idx<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &SparseProblem<R, O>,
+    pivot_column: Variable,
+) -> Option<usize> {
+    problem
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| (idx, row.equation.coefficients.get(pivot_column), row))
+        .filter(|(_, coeff, _)| coeff.is_positive())
+        .map(|(idx, coeff, row)| (idx, row.equation.constraint.clone() / coeff))
+        .min_by(|(_, r1), (_, r2)| r1.cmp(r2))
+        .unzip()
+        .0
+}
+
+fn pivot<R: RowValue, O: ObjectiveValue<R>>(
+    problem: &mut SparseProblem<R, O>,
+    pivot_row_idx: usize,
+    variable: Variable,
+) {
+    let pivot_row = &mut problem.rows[pivot_row_idx];
+    let coeff = pivot_row.equation.coefficients.get(variable);
+    pivot_row.equation.coefficients.divide(&coeff);
+    pivot_row.equation.constraint = pivot_row.equation.constraint.clone() / coeff;
+    pivot_row.basic_variable = variable;
+    let (pivot_row, other_rows) = tabular::iter_around_mut(&mut problem.rows, pivot_row_idx);
+    let pivot_equation = &pivot_row.equation;
+    for row in other_rows {
+        let factor = row.equation.coefficients.get(variable);
+        if factor == R::zero() {
+            continue;
+        }
+        row.equation
+            .coefficients
+            .add_scaled(&-factor.clone(), &pivot_equation.coefficients);
+        row.equation.constraint =
+            row.equation.constraint.clone() + -(factor * pivot_equation.constraint.clone());
+    }
+    let objective = &mut problem.objective_equation;
+    let factor = objective.coefficients[variable].clone();
+    for (var, value) in &pivot_equation.coefficients.entries {
+        objective.coefficients[*var] =
+            objective.coefficients[*var].clone() + -(factor.clone() * value.clone());
+    }
+    objective.constraint =
+        objective.constraint.clone() + -(factor * pivot_equation.constraint.clone());
+}
+
+/// A solve that stops with an artificial variable basic at a positive level
+/// is infeasible.
+fn result<R: RowValue, O: ObjectiveValue<R>>(
+    problem: SparseProblem<R, O>,
+    status: SolveStatus,
+    iterations: usize,
+) -> SolveResult<R, O, SparseProblem<R, O>> {
+    let mut result = SolveResult::from_problem(problem, status, iterations);
+    if status == SolveStatus::Optimal && !result.infeasible_constraints.is_empty() {
+        result.status = SolveStatus::Infeasible;
+    }
+    result
+}
@@ -0,0 +1,167 @@
+use crate::simplex::sparse::SparseConstraint;
+use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
+use crate::simplex::tabular::primal::twophase::PlainObjectiveValue;
+use crate::simplex::tabular::primal::{self, PrimalProblem};
+use crate::simplex::tabular::sparse::{self as sut, SparseProblem};
+use crate::simplex::tabular::{EmptyObserver, Problem, SolveOptions, SolveStatus};
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+use crate::simplex::value::{self, Value};
+use crate::simplex::{Constraint, ObjectiveSense};
+
+fn covering() -> (Vec<Value>, Vec<Constraint<Value>>) {
+    (
+        vec![frac(2, 1), frac(3, 1), frac(4, 1)],
+        vec![
+            lower_bound_constraint(vec![frac(1, 1), frac(1, 1), frac(1, 1)], frac(4, 1)),
+            equality_constraint(vec![frac(1, 1), -frac(1, 1), value::zero()], value::zero()),
+            upper_bound_constraint(vec![frac(1, 1), value::zero(), frac(2, 1)], frac(5, 1)),
+        ],
+    )
+}
+
+fn sparse_constraints(constraints: &[Constraint<Value>]) -> Vec<SparseConstraint<Value>> {
+    constraints.iter().map(SparseConstraint::from).collect()
+}
+
+#[test]
+fn builds_the_same_tableau_as_dense_rows() {
+    let (objective_coeffs, constraints) = covering();
+    let sparse: SparseProblem<Value, MObjectiveValue> = SparseProblem::with_sense(
+        ObjectiveSense::Minimize,
+        &objective_coeffs,
+        &sparse_constraints(&constraints),
+    )
+    .unwrap();
+    let dense =
+        PrimalProblem::with_sense(ObjectiveSense::Minimize, &objective_coeffs, &constraints);
+    assert_eq!(dense, sparse.to_dense());
+    let sparse: SparseProblem<Value, PlainObjectiveValue<Value>> =
+        SparseProblem::new(&objective_coeffs, &sparse_constraints(&constraints)).unwrap();
+    assert_eq!(
+        Problem::new(&objective_coeffs, &constraints),
+        sparse.to_dense()
+    );
+}
+
+#[test]
+fn stores_one_entry_per_slack_column() {
+    let row_count = 50;
+    let constraints: Vec<Constraint<Value>> = (0..row_count)
+        .map(|idx| {
+            let mut coefficients = vec![value::zero(); row_count];
+            coefficients[idx] = frac(1, 1);
+            upper_bound_constraint(coefficients, frac(idx as u64 + 1, 1))
+        })
+        .collect();
+    let problem: SparseProblem<Value, MObjectiveValue> = SparseProblem::new(
+        &vec![frac(1, 1); row_count],
+        &sparse_constraints(&constraints),
+    )
+    .unwrap();
+    assert!(problem
+        .rows
+        .iter()
+        .all(|row| row.equation.coefficients.entries.len() == 2));
+    let result = sut::solve(problem);
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(
+        MObjectiveValue::from(frac((row_count * (row_count + 1) / 2) as u64, 1)),
+        result.objective_value
+    );
+    assert!(result
+        .tableau
+        .rows
+        .iter()
+        .all(|row| row.equation.coefficients.entries.len() == 2));
+}
+
+#[test]
+fn matches_dense_solver() {
+    let (objective_coeffs, constraints) = covering();
+    let expected = primal::solve(
+        PrimalProblem::with_sense(ObjectiveSense::Minimize, &objective_coeffs, &constraints),
+        &mut EmptyObserver {},
+    );
+    let result = sut::solve(
+        SparseProblem::with_sense(
+            ObjectiveSense::Minimize,
+            &objective_coeffs,
+            &sparse_constraints(&constraints),
+        )
+        .unwrap(),
+    );
+    assert_eq!(SolveStatus::Optimal, result.status);
+    assert_eq!(expected.objective_value, result.objective_value);
+    assert_eq!(expected.point, result.point);
+    assert_eq!(expected.basis, result.basis);
+    assert_eq!(expected.iterations, result.iterations);
+    assert_eq!(expected.dual_values, result.dual_values);
+    assert_eq!(
+        expected.tableau.objective_equation,
+        result.tableau.objective_equation
+    );
+}
+
+#[test]
+fn reports_infeasible_and_unbounded_problems() {
+    let infeasible = [
+        upper_bound_constraint(vec![frac(1, 1)], frac(1, 1)),
+        lower_bound_constraint(vec![frac(1, 1)], frac(2, 1)),
+    ];
+    let result = sut::solve(
+        SparseProblem::<Value, MObjectiveValue>::new(
+            &vec![frac(1, 1)],
+            &sparse_constraints(&infeasible),
+        )
+        .unwrap(),
+    );
+    assert_eq!(SolveStatus::Infeasible, result.status);
+    assert_eq!(vec![1], result.infeasible_constraints);
+    let unbounded = [upper_bound_constraint(
+        vec![frac(1, 1), -frac(1, 1)],
+        frac(1, 1),
+    )];
+    let result = sut::solve(
+        SparseProblem::<Value, MObjectiveValue>::new(
+            &vec![frac(1, 1), value::zero()],
+            &sparse_constraints(&unbounded),
+        )
+        .unwrap(),
+    );
+    assert_eq!(SolveStatus::Unbounded, result.status);
+}
+
+#[test]
+fn stops_at_iteration_limit() {
+    let (objective_coeffs, constraints) = covering();
+    let problem: SparseProblem<Value, MObjectiveValue> =
+        SparseProblem::new(&objective_coeffs, &sparse_constraints(&constraints)).unwrap();
+    let options = SolveOptions { iteration_limit: 0 };
+    let result = sut::solve_with_options(problem, &options);
+    assert_eq!(SolveStatus::IterationLimit, result.status);
+    assert_eq!(0, result.iterations);
+}
+
+#[test]
+fn rejects_entries_past_the_decision_variables() {
+    let constraints = vec![
+        SparseConstraint::from(&upper_bound_constraint(
+            vec![frac(1, 1), frac(1, 1)],
+            frac(4, 1),
+        )),
+        SparseConstraint::from(&upper_bound_constraint(
+            vec![frac(1, 1), frac(1, 1), frac(1, 1)],
+            frac(4, 1),
+        )),
+    ];
+    assert_eq!(
+        Err(sut::ColumnOutOfRange {
+            constraint: 1,
+            column: 2,
+            decision_var_count: 2,
+        }),
+        SparseProblem::<Value, MObjectiveValue>::new(&vec![frac(1, 1), frac(1, 1)], &constraints)
+    );
+}
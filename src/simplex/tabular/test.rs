@@ -1,4 +1,5 @@
-
+mod add_constraint;
+mod append_row;
 mod initial_point;
 mod normalize_equation;
 mod reduce_equations;
\ No newline at end of file
@@ -0,0 +1,63 @@
+use crate::simplex::tabular::primal::PrimalProblem;
+use crate::simplex::test::{
+    equality_constraint, frac, lower_bound_constraint, upper_bound_constraint,
+};
+
+fn make_problem() -> PrimalProblem {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(
+        vec![frac(1, 1), frac(1, 1)],
+        frac(4, 1),
+    )];
+    PrimalProblem::new(&objective_function, &fn_constraints)
+}
+
+#[test]
+fn adds_upper_bound_constraint_as_one_row() {
+    let mut problem = make_problem();
+    problem.add_constraint(&upper_bound_constraint(
+        vec![frac(1, 1), frac(0, 1)],
+        frac(3, 1),
+    ));
+    assert_eq!(2, problem.rows.len());
+    assert_eq!(3, problem.rows[1].basic_variable);
+    assert_eq!(
+        vec![frac(1, 1), frac(0, 1), frac(0, 1), frac(1, 1)],
+        problem.rows[1].equation.coefficients
+    );
+    assert_eq!(frac(3, 1), problem.rows[1].equation.constraint);
+}
+
+#[test]
+fn negates_lower_bound_constraint() {
+    let mut problem = make_problem();
+    problem.add_constraint(&lower_bound_constraint(
+        vec![frac(0, 1), frac(1, 1)],
+        frac(1, 1),
+    ));
+    assert_eq!(
+        vec![frac(0, 1), -frac(1, 1), frac(0, 1), frac(1, 1)],
+        problem.rows[1].equation.coefficients
+    );
+    assert_eq!(-frac(1, 1), problem.rows[1].equation.constraint);
+}
+
+#[test]
+fn adds_equality_constraint_as_two_rows() {
+    let mut problem = make_problem();
+    problem.add_constraint(&equality_constraint(vec![frac(1, 1)], frac(2, 1)));
+    assert_eq!(3, problem.rows.len());
+    assert_eq!(
+        vec![3, 4],
+        vec![
+            problem.rows[1].basic_variable,
+            problem.rows[2].basic_variable
+        ]
+    );
+    assert_eq!(frac(2, 1), problem.rows[1].equation.constraint);
+    assert_eq!(-frac(2, 1), problem.rows[2].equation.constraint);
+    assert_eq!(
+        vec![-frac(1, 1), frac(0, 1), frac(0, 1), frac(0, 1), frac(1, 1)],
+        problem.rows[2].equation.coefficients
+    );
+}
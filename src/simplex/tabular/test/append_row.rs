@@ -0,0 +1,60 @@
+use crate::simplex::tabular::primal::PrimalProblem;
+use crate::simplex::tabular::{self as sut};
+use crate::simplex::test::{frac, lower_bound_constraint, upper_bound_constraint};
+
+#[test]
+fn appends_row_with_slack_after_existing_slacks() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![
+        upper_bound_constraint(vec![frac(1, 1), frac(0, 1)], frac(4, 1)),
+        lower_bound_constraint(vec![frac(0, 1), frac(1, 1)], frac(1, 1)),
+    ];
+    let mut problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    sut::append_row(
+        &mut problem,
+        vec![frac(1, 1), frac(1, 1), frac(0, 1), frac(0, 1), frac(0, 1)],
+        frac(6, 1),
+    );
+    assert_eq!(3, problem.rows.len());
+    assert_eq!(4, problem.rows[2].basic_variable);
+    assert_eq!(
+        vec![
+            frac(0, 1),
+            frac(1, 1),
+            frac(0, 1),
+            frac(1, 1),
+            frac(0, 1),
+            -frac(1, 1)
+        ],
+        problem.rows[1].equation.coefficients
+    );
+    assert_eq!(6, problem.objective_equation.coefficients.len());
+    assert_eq!(vec![3], problem.artificial_variables);
+}
+
+#[test]
+fn rewrites_appended_row_in_nonbasic_columns() {
+    let objective_function = vec![frac(1, 1), frac(1, 1)];
+    let fn_constraints = vec![upper_bound_constraint(
+        vec![frac(1, 1), frac(2, 1)],
+        frac(4, 1),
+    )];
+    let mut problem = PrimalProblem::new(&objective_function, &fn_constraints);
+    sut::pivot(&mut problem, 0, 0);
+    sut::append_row(
+        &mut problem,
+        vec![frac(1, 1), frac(0, 1), frac(0, 1)],
+        frac(3, 1),
+    );
+    let row = &problem.rows[1];
+    assert_eq!(3, row.basic_variable);
+    assert_eq!(
+        vec![frac(0, 1), -frac(2, 1), -frac(1, 1), frac(1, 1)],
+        row.equation.coefficients
+    );
+    assert_eq!(-frac(1, 1), row.equation.constraint);
+    assert_eq!(
+        vec![frac(4, 1), frac(0, 1), frac(0, 1), -frac(1, 1)],
+        problem.point
+    );
+}
@@ -1,6 +1,6 @@
 use crate::simplex::{
     tabular,
-    test::{frac, upper_bound_constraint},
+    test::{frac, lower_bound_constraint, upper_bound_constraint},
     value::Value,
     Constraint,
 };
@@ -46,3 +46,15 @@ fn finds_initial_point_with_two_constraints_two_variables() {
     let expected_point = vec![frac(0, 1), frac(0, 1), frac(6, 1), frac(7, 1)];
     assert_eq!(expected_point, point);
 }
+
+#[test]
+fn finds_initial_point_with_surplus_variable() {
+    let coeffs = vec![frac(1, 1), frac(1, 1)];
+    let constraints = vec![
+        lower_bound_constraint(vec![frac(1, 1), frac(2, 1)], frac(6, 1)),
+        upper_bound_constraint(vec![frac(3, 1), frac(4, 1)], frac(7, 1)),
+    ];
+    let point = tabular::initial_point(&coeffs, &constraints);
+    let expected_point = vec![frac(0, 1), frac(0, 1), frac(6, 1), frac(7, 1), frac(0, 1)];
+    assert_eq!(expected_point, point);
+}
@@ -1,17 +1,17 @@
 mod normalize_equation {
     use crate::simplex::{
-        tabular::{self as sut, Equation, SimplexRow, primal::{MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from}},
-        test::frac,
+        tabular::{self as sut, Equation, SimplexRow, primal::{MObjectiveEquation, mobjectivevalue::test::mvalue_from}},
+        test::{frac, tableau},
     };
 
     #[test]
     fn sets_single_coefficient_to_one() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(5, 1)],
@@ -19,20 +19,20 @@ mod normalize_equation {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::normalize_equation(&mut problem, 0, 0);
         assert_eq!(vec![frac(1, 1)], problem.rows[0].equation.coefficients);
     }
 
     #[test]
     fn divides_other_coefficients_by_pivot() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(2, 1), frac(4, 1), frac(6, 1)],
@@ -40,8 +40,8 @@ mod normalize_equation {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::normalize_equation(&mut problem, 0, 0);
         assert_eq!(
             vec![frac(1, 1), frac(2, 1), frac(3, 1)],
@@ -51,12 +51,12 @@ mod normalize_equation {
 
     #[test]
     fn normalizes_on_non_first_variable() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(3, 1), frac(6, 1), frac(9, 1)],
@@ -64,8 +64,8 @@ mod normalize_equation {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::normalize_equation(&mut problem, 0, 1);
         assert_eq!(
             vec![frac(1, 2), frac(1, 1), frac(3, 2)],
@@ -75,12 +75,12 @@ mod normalize_equation {
 
     #[test]
     fn leaves_other_coefficients_unchanged_when_pivot_is_one() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1), frac(3, 1), frac(5, 1)],
@@ -88,8 +88,8 @@ mod normalize_equation {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::normalize_equation(&mut problem, 0, 0);
         assert_eq!(
             vec![frac(1, 1), frac(3, 1), frac(5, 1)],
@@ -99,12 +99,12 @@ mod normalize_equation {
 
     #[test]
     fn normalizes_correct_row_when_multiple_rows_exist() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -122,8 +122,8 @@ mod normalize_equation {
                     ratio: frac(0, 1),
                 },
             ],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::normalize_equation(&mut problem, 1, 1);
         assert_eq!(
             vec![frac(2, 1), frac(4, 1)],
@@ -1,17 +1,17 @@
 mod reduce_equations {
     use crate::simplex::{
-        tabular::{self as sut, Equation, SimplexRow, primal::{MObjectiveEquation, PrimalProblem, mobjectivevalue::test::mvalue_from}},
-        test::frac,
+        tabular::{self as sut, Equation, SimplexRow, primal::{MObjectiveEquation, mobjectivevalue::test::mvalue_from}},
+        test::{frac, tableau},
     };
 
     #[test]
     fn reduces_objective_equation() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(3, 1), mvalue_from(2, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1), frac(1, 2)],
@@ -19,8 +19,8 @@ mod reduce_equations {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::reduce_equations(&mut problem, 0, 0);
         assert_eq!(
             vec![mvalue_from(0, 1), mvalue_from(1, 2)],
@@ -31,12 +31,12 @@ mod reduce_equations {
 
     #[test]
     fn leaves_pivot_row_unchanged() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(1, 1), mvalue_from(1, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(1, 1), frac(2, 1)],
@@ -44,8 +44,8 @@ mod reduce_equations {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::reduce_equations(&mut problem, 0, 0);
         assert_eq!(
             vec![frac(1, 1), frac(2, 1)],
@@ -56,12 +56,12 @@ mod reduce_equations {
 
     #[test]
     fn reduces_non_pivot_row() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(0, 1), mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -79,8 +79,8 @@ mod reduce_equations {
                     ratio: frac(0, 1),
                 },
             ],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::reduce_equations(&mut problem, 0, 0);
         assert_eq!(
             vec![frac(0, 1), -frac(2, 1)],
@@ -91,12 +91,12 @@ mod reduce_equations {
 
     #[test]
     fn reduces_multiple_non_pivot_rows() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(0, 1), mvalue_from(0, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![
+            vec![
                 SimplexRow {
                     basic_variable: 0,
                     equation: Equation {
@@ -122,8 +122,8 @@ mod reduce_equations {
                     ratio: frac(0, 1),
                 },
             ],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::reduce_equations(&mut problem, 1, 0);
         assert_eq!(
             vec![frac(0, 1), -frac(5, 1)],
@@ -144,12 +144,12 @@ mod reduce_equations {
 
     #[test]
     fn reduces_on_non_first_variable() {
-        let mut problem = PrimalProblem {
-            objective_equation: MObjectiveEquation {
+        let mut problem = tableau(
+            MObjectiveEquation {
                 coefficients: vec![mvalue_from(2, 1), mvalue_from(4, 1)],
                 constraint: mvalue_from(0, 1),
             },
-            rows: vec![SimplexRow {
+            vec![SimplexRow {
                 basic_variable: 0,
                 equation: Equation {
                     coefficients: vec![frac(3, 1), frac(1, 1)],
@@ -157,8 +157,8 @@ mod reduce_equations {
                 },
                 ratio: frac(0, 1),
             }],
-            point: vec![],
-        };
+            vec![],
+        );
         sut::reduce_equations(&mut problem, 0, 1);
         assert_eq!(
             vec![-mvalue_from(10, 1), mvalue_from(0, 1)],
@@ -112,7 +112,7 @@ fn format_row(row_data: &RowStrings, widths: &ColumnWidths) -> String {
     row
 }
 
-fn stringify_coefficients<T: Display>(coefficients: &Vec<T>) -> Vec<String> {
+fn stringify_coefficients<T: Display>(coefficients: &[T]) -> Vec<String> {
     coefficients.iter().map(|c| c.to_string()).collect()
 }
 
@@ -3,21 +3,23 @@ use crate::simplex::tabular::primal::mobjectivevalue::test::mvalue_from;
 use crate::simplex::tabular::primal::mobjectivevalue::MObjectiveValue;
 use crate::simplex::tabular::write_observer::{self as sut};
 use crate::simplex::tabular::{Equation, Problem, ProblemObserver, SimplexRow};
-use crate::simplex::test::frac;
+use crate::simplex::test::{frac, tableau};
 use crate::simplex::value::Value;
 
 type MProblem = Problem<Value, MObjectiveValue>;
 type MObjectiveEquation = Row<MObjectiveValue>;
 
 fn make_one_variable_problem() -> MProblem {
-    MProblem {
-        objective_equation: MObjectiveEquation {
+    let mut problem = tableau(
+        MObjectiveEquation {
             coefficients: vec![-mvalue_from(5, 1)],
             constraint: mvalue_from(0, 1),
         },
-        rows: vec![],
-        point: vec![frac(0, 1)],
-    }
+        vec![],
+        vec![frac(0, 1)],
+    );
+    problem.decision_var_count = 1;
+    problem
 }
 
 #[test]
@@ -38,8 +40,8 @@ fn formats_objective_row_only() {
 
 #[test]
 fn formats_two_variable_two_constraint_problem() {
-    let problem = MProblem {
-        objective_equation: MObjectiveEquation {
+    let mut problem = tableau(
+        MObjectiveEquation {
             coefficients: vec![
                 -mvalue_from(500, 1),
                 -mvalue_from(4, 1),
@@ -48,7 +50,7 @@ fn formats_two_variable_two_constraint_problem() {
             ],
             constraint: mvalue_from(0, 1),
         },
-        rows: vec![
+        vec![
             SimplexRow {
                 basic_variable: 2,
                 equation: Equation {
@@ -66,8 +68,9 @@ fn formats_two_variable_two_constraint_problem() {
                 ratio: frac(6, 1),
             },
         ],
-        point: vec![frac(0, 1); 4],
-    };
+        vec![frac(0, 1); 4],
+    );
+    problem.decision_var_count = 2;
     let mut output = Vec::new();
     {
         let mut observer = sut::WriteObserver::new(&mut output);
@@ -86,12 +89,12 @@ fn formats_two_variable_two_constraint_problem() {
 
 #[test]
 fn formats_with_one_constraint_row() {
-    let problem = MProblem {
-        objective_equation: MObjectiveEquation {
+    let mut problem = tableau(
+        MObjectiveEquation {
             coefficients: vec![-mvalue_from(5, 1)],
             constraint: mvalue_from(0, 1),
         },
-        rows: vec![SimplexRow {
+        vec![SimplexRow {
             basic_variable: 1,
             equation: Equation {
                 coefficients: vec![frac(2, 1)],
@@ -99,8 +102,9 @@ fn formats_with_one_constraint_row() {
             },
             ratio: frac(5, 1),
         }],
-        point: vec![frac(0, 1)],
-    };
+        vec![frac(0, 1)],
+    );
+    problem.decision_var_count = 1;
     let mut output = Vec::new();
     {
         let mut observer = sut::WriteObserver::new(&mut output);
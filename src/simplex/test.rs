@@ -1,7 +1,10 @@
 use fraction::Fraction;
 
+use crate::simplex::objectivevalue::ObjectiveValue;
+use crate::simplex::rowvalue::Row;
+use crate::simplex::tabular::{Problem, SimplexRow};
 use crate::simplex::value::Value;
-use crate::simplex::{Coefficients, Constraint, Operator};
+use crate::simplex::{Coefficients, Constraint, ObjectiveSense, Operator};
 
 pub fn frac(n: u64, d: u64) -> Value {
     Fraction::new(n, d)
@@ -18,6 +21,17 @@ pub fn upper_bound_constraint(
     }
 }
 
+pub fn lower_bound_constraint(
+    coefficients: Coefficients<Value>,
+    bound: Value,
+) -> Constraint<Value> {
+    Constraint {
+        operator: Operator::GREATERTHANEQUAL,
+        coefficients,
+        bound,
+    }
+}
+
 pub fn equality_constraint(coefficients: Coefficients<Value>, bound: Value) -> Constraint<Value> {
     Constraint {
         operator: Operator::EQUAL,
@@ -25,3 +39,20 @@ pub fn equality_constraint(coefficients: Coefficients<Value>, bound: Value) -> C
         bound,
     }
 }
+
+/// A maximizing tableau laid out by hand, with no decision variables or
+/// artificial variables marked. Tests that need them set the fields after.
+pub fn tableau<O: ObjectiveValue<Value>>(
+    objective_equation: Row<O>,
+    rows: Vec<SimplexRow<Value>>,
+    point: Coefficients<Value>,
+) -> Problem<Value, O> {
+    Problem {
+        objective_equation,
+        rows,
+        point,
+        decision_var_count: 0,
+        artificial_variables: vec![],
+        sense: ObjectiveSense::Maximize,
+    }
+}
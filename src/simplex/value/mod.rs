@@ -1,6 +1,8 @@
+use std::fmt::Display;
+
 use fraction::{ConstOne, ConstZero, Fraction};
 
-use crate::simplex::rowvalue::RowValue;
+use crate::simplex::rowvalue::{Decimal, RowValue};
 
 pub type Value = Fraction;
 
@@ -12,6 +14,12 @@ pub fn one() -> Value {
     Fraction::ONE
 }
 
+/// Formats a value, or an objective value built from values, as a decimal,
+/// exactly when it terminates.
+pub fn format_decimal(value: &impl Display) -> String {
+    format!("{:.17}", value)
+}
+
 impl RowValue for Value {
     fn zero() -> Self {
         zero()
@@ -24,4 +32,20 @@ impl RowValue for Value {
     fn is_finite(&self) -> bool {
         !matches!(self, Fraction::Infinity(_) | Fraction::NaN)
     }
+
+    /// `None` when the digits or the power of ten overflow a `u64`.
+    fn from_decimal(decimal: &Decimal) -> Option<Self> {
+        let numerator = decimal.digits.parse::<u64>().ok()?;
+        let power = 10u64.checked_pow(decimal.exponent.unsigned_abs())?;
+        let magnitude = if decimal.exponent >= 0 {
+            Fraction::from(numerator.checked_mul(power)?)
+        } else {
+            Fraction::new(numerator, power)
+        };
+        Some(if decimal.negative {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
 }